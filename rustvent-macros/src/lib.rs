@@ -1,81 +1,223 @@
-use core::panic;
-
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, format_ident, ToTokens};
-use syn::{self, DeriveInput, Data, Type};
+use quote::{quote, quote_spanned, format_ident};
+use syn::{self, parse_macro_input, spanned::Spanned, DeriveInput, Data, Field, GenericArgument, Index, Member, PathArguments, PathSegment, Type};
+
+/// The last segment of the path of the types recognized as events, e.g. `rustvent::events::Event<u32>`.
+const EVENT_TYPES: [&str; 3] = ["Event", "EventAsync", "ReentrantEvent"];
+
+/// The types that can never be events, so that marking a field of one of them with `#[event]` is reported by the macro.
+const PRIMITIVE_TYPES: [&str; 17] = [
+    "bool", "char", "str", "String", "f32", "f64",
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128",
+];
+
+/// The last segment of the path of `EventFuture`, whose fields have to be left out with `#[event(skip)]`.
+const EVENT_FUTURE_TYPE: &str = "EventFuture";
+
+/// How the `on_<field>` method of an event field is generated.
+enum EventKind {
+    /// `on_<field>(&mut self)`, for events without a payload.
+    Unit,
+    /// `on_<field>(&mut self, args)`, handing `args` to the subscribers.
+    WithArgs,
+}
 
-#[proc_macro_derive(Event)]
+/// Generates a `Rustvent<Struct>` trait with an `on_<field>` method notifying each event field of the struct.
+///
+/// Fields of type `Event`, `EventAsync` or `ReentrantEvent` are recognized through any path, e.g.
+/// `rustvent::events::Event<u32>`. Fields of another type, such as a type alias, are included with
+/// `#[event]`, and a recognized field is left out with `#[event(skip)]`. Events of `()` get an
+/// `on_<field>()` method; other events, and every field marked with `#[event]` whose type is not
+/// recognized, get an `on_<field>(&args)` method. The fields of a tuple struct get `on_0`, `on_1`...
+/// Marking a field with `#[event]` whose type can never be an event, such as `u32`, is an error.
+///
+/// `EventFuture` fields are notified asynchronously, so they are reported as errors unless left out
+/// with `#[event(skip)]`.
+///
+/// The trait has the generics of the struct, so generic and lifetime-parameterized structs are supported.
+#[proc_macro_derive(Event, attributes(event))]
 pub fn event_macro_derive(item: TokenStream) -> TokenStream {
-    let input = proc_macro2::TokenStream::from(item);
-    let DeriveInput { ident, data, .. } = syn::parse2(input).unwrap();
+    let input = parse_macro_input!(item as DeriveInput);
+
+    expand(input)
+    .unwrap_or_else(syn::Error::into_compile_error)
+    .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let DeriveInput { vis, ident, mut generics, data, .. } = input;
+
+    let fields = match data {
+        Data::Struct(data) => data.fields,
+        Data::Enum(data) => return Err(syn::Error::new(data.enum_token.span, "`#[derive(Event)]` is only supported on structs")),
+        Data::Union(data) => return Err(syn::Error::new(data.union_token.span, "`#[derive(Event)]` is only supported on structs")),
+    };
+
     let mut trait_func_stream = TokenStream2::default();
     let mut impl_func_stream = TokenStream2::default();
+    let mut errors: Option<syn::Error> = None;
 
     let ftrait_name = format_ident!("Rustvent{}", &ident);
 
-    let output = if let Data::Struct(x) = data {
+    for (index, field) in fields.iter().enumerate() {
+        let kind = match event_kind(field) {
+            Ok(Some(kind)) => kind,
+            Ok(None) => continue,
+            Err(err) => {
+                match &mut errors {
+                    Some(errors) => errors.combine(err),
+                    None => errors = Some(err),
+                }
+                continue;
+            },
+        };
 
-        let fields = x.fields.iter().map(|f| {
-            (&f.ident, &f.ty)
-        });
-  
-        for (field, ty) in fields.clone().into_iter() {
-            if !is_typeof_event(ty) { continue; }
+        let member = match &field.ident {
+            Some(name) => Member::Named(name.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        let fname = match &member {
+            Member::Named(name) => format_ident!("on_{}", name),
+            Member::Unnamed(index) => format_ident!("on_{}", index.index),
+        };
+        let ty = &field.ty;
+
+        // The generated methods name the field type, so the event must be a Notifier for every
+        // instantiation of the struct, e.g. `EventAsync<T>` needs `T: Sync`.
+        let bound = quote_spanned! { ty.span()=> #ty: ::rustvent::notifier::Notifier };
+        generics.make_where_clause().predicates.push(syn::parse2(bound)?);
+
+        let output = quote! { <#ty as ::rustvent::notifier::Notifier>::Output };
+        let (signature, args) = match kind {
+            EventKind::Unit => (
+                quote! { fn #fname(&mut self) -> #output },
+                quote! { &() }
+            ),
+            EventKind::WithArgs => (
+                quote! { fn #fname(&mut self, args: &<#ty as ::rustvent::notifier::Notifier>::Args) -> #output },
+                quote! { args }
+            ),
+        };
 
-            let fname = format_ident!("on_{}", field.clone().unwrap());
+        trait_func_stream.extend::<TokenStream2>(
+            quote! {
+                #signature;
+            }
+        );
 
-            trait_func_stream.extend::<TokenStream2>(
-                quote! {
-                    fn #fname(&mut self);
+        impl_func_stream.extend::<TokenStream2>(
+            quote! {
+                #signature {
+                    ::rustvent::notifier::Notifier::notify_with(&mut self.#member, #args)
                 }
-            );
-        }
+            }
+        );
+    }
 
-        for (field, ty) in fields.clone().into_iter() {
-            if !is_typeof_event(ty) { continue; }
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
 
-            let name = field.clone().unwrap();
-            let fname = format_ident!("on_{}", field.clone().unwrap());
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-            impl_func_stream.extend::<TokenStream2>(
-                quote! {
-                    fn #fname(&mut self) {
-                        self.#name.notify();
-                    }
-                }
-            );
+    // Not every event of a struct has to be notified, so the methods of the unused ones are allowed
+    // instead of leaving their fields reported as never read.
+    let trait_def = quote! {
+        #[allow(dead_code)]
+        #vis trait #ftrait_name #impl_generics #where_clause {
+            #trait_func_stream
         }
+    };
 
-        let trait_def = quote! {
-            pub trait #ftrait_name {
-                #trait_func_stream
-            }
-        };
+    let impl_def = quote! {
+        #[allow(dead_code)]
+        impl #impl_generics #ftrait_name #ty_generics for #ident #ty_generics #where_clause {
+            #impl_func_stream
+        }
+    };
 
-        let impl_def = quote! {
-            impl #ftrait_name for #ident {
-                #impl_func_stream
-            }
-        };
+    Ok(quote! {
+        #trait_def
+        #impl_def
+    })
+}
+
+/// Whether `field` is an event, as told by its `#[event]` attribute or else by its type.
+fn event_kind(field: &Field) -> syn::Result<Option<EventKind>> {
+    let mut marked = None;
+    let mut skipped = None;
 
-        quote! {
-            #trait_def
-            #impl_def
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("event")) {
+        if attr.meta.require_path_only().is_ok() {
+            marked = Some(attr);
+            continue;
         }
-    } else {
-        panic!("Macro was not used on a Struct");
-    };
 
-    output.into()
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skipped = Some(attr);
+                Ok(())
+            } else {
+                Err(meta.error("expected `#[event]` or `#[event(skip)]`"))
+            }
+        })?;
+    }
 
+    match (marked, skipped) {
+        (Some(_), Some(skipped)) => Err(syn::Error::new_spanned(skipped, "a field cannot be both `#[event]` and `#[event(skip)]`")),
+        (_, Some(_)) => Ok(None),
+        _ if last_segment(&field.ty).is_some_and(|segment| segment.ident == EVENT_FUTURE_TYPE) => Err(syn::Error::new_spanned(
+            &field.ty,
+            "`EventFuture` fields are not supported by `#[derive(Event)]`, leave them out with `#[event(skip)]`"
+        )),
+        (marked, None) => match recognized_kind(&field.ty) {
+            Some(kind) => Ok(Some(kind)),
+            None if marked.is_some() && !may_be_event(&field.ty) => Err(syn::Error::new_spanned(
+                &field.ty,
+                "`#[event]` is only supported on fields whose type is an event, e.g. an alias of `Event<T>`"
+            )),
+            None if marked.is_some() => Ok(Some(EventKind::WithArgs)),
+            None => Ok(None),
+        },
+    }
 }
 
-fn is_typeof_event(ty: &Type) -> bool {
-    match ty {
-        Type::Path(type_path) if type_path.clone().into_token_stream().to_string() == "Event" => {
-            true
+/// The kind of event `ty` names, if the last segment of its path is one of [EVENT_TYPES].
+fn recognized_kind(ty: &Type) -> Option<EventKind> {
+    let segment = last_segment(ty)?;
+    if !EVENT_TYPES.iter().any(|name| segment.ident == name) {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::None => Some(EventKind::Unit),
+        PathArguments::AngleBracketed(generics) => match generics.args.first() {
+            Some(GenericArgument::Type(Type::Tuple(tuple))) if tuple.elems.is_empty() && generics.args.len() == 1 => Some(EventKind::Unit),
+            _ => Some(EventKind::WithArgs),
         },
-        _ => false
+        PathArguments::Parenthesized(_) => None,
+    }
+}
+
+/// Whether `ty` may name an event, e.g. through a type alias. Primitives and types that are not paths never do.
+fn may_be_event(ty: &Type) -> bool {
+    match ty {
+        Type::Paren(paren) => may_be_event(&paren.elem),
+        Type::Group(group) => may_be_event(&group.elem),
+        Type::Path(type_path) => type_path.qself.is_some()
+            || type_path.path.segments.len() > 1
+            || last_segment(ty).is_none_or(|segment| !PRIMITIVE_TYPES.iter().any(|name| segment.ident == name)),
+        _ => false,
     }
-}
\ No newline at end of file
+}
+
+/// The last segment of the path `ty` names, e.g. `Event<u32>` for `rustvent::events::Event<u32>`.
+fn last_segment(ty: &Type) -> Option<&PathSegment> {
+    let Type::Path(type_path) = ty else { return None };
+    if type_path.qself.is_some() {
+        return None;
+    }
+
+    type_path.path.segments.last()
+}
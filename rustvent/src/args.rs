@@ -0,0 +1,66 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Implemented by payloads that let subscribers stop the notification of an [Event](crate::events::Event).
+/// Only [notify_cancellable()](crate::events::Event::notify_cancellable()) honors it: the other notify methods
+/// hand the payload to every subscriber.
+pub trait Cancellable {
+    /// Whether the subscribers that have not been notified yet should be skipped.
+    fn is_propagation_stopped(&self) -> bool;
+}
+
+/// A payload that any subscriber can mark as handled or cancel. Once either flag is set, an [Event](crate::events::Event)
+/// notified with [notify_cancellable()](crate::events::Event::notify_cancellable()) stops notifying the remaining
+/// subscribers, and the publisher can read the final state back from the args it passed in.
+/// # Examples
+/// ```
+/// let mut closing: Event<EventArgs<WindowId>> = Event::default();
+/// closing.subscribe_as_fn_with(|args| if has_unsaved_changes(args.data) { args.cancel() });
+///
+/// let args = EventArgs::new(window_id);
+/// closing.notify_cancellable(&args);
+///
+/// if !args.is_cancelled() {
+///     close(window_id);
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct EventArgs<T = ()> {
+    pub data: T,
+    handled: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+impl<T> EventArgs<T> {
+    pub fn new(data: T) -> EventArgs<T> {
+        EventArgs { data, handled: AtomicBool::new(false), cancelled: AtomicBool::new(false) }
+    }
+
+    /// Marks the event as handled, so no other subscriber needs to act on it.
+    pub fn set_handled(&self) {
+        self.handled.store(true, Ordering::Release);
+    }
+
+    pub fn is_handled(&self) -> bool {
+        self.handled.load(Ordering::Acquire)
+    }
+
+    /// Vetoes the action the event announces, e.g. closing a window.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns the payload, discarding the flags.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T> Cancellable for EventArgs<T> {
+    fn is_propagation_stopped(&self) -> bool {
+        self.is_handled() || self.is_cancelled()
+    }
+}
@@ -0,0 +1,266 @@
+use std::{any::{Any, TypeId}, cell::RefCell, collections::HashMap, rc::Rc, sync::{Arc, Mutex, PoisonError, RwLock}};
+
+use crate::{
+    subscriber::{Subscriber, SubscriberMut, TrySubscriber, SubscriberAsync, SubscriberAsyncMut, TrySubscriberAsync},
+    subscription::SubscriptionId,
+    error::RustventError,
+    events::{Event, EventConfig, Clear},
+    event_async::EventAsync,
+    report::NotificationReport
+};
+
+/// Creates the [EventConfig] of each event of an [EventBus], see [with_config()](EventBus::with_config()).
+pub type ConfigFactory = Box<dyn Fn() -> EventConfig>;
+
+/// Creates the [EventConfig] of each event of an [EventBusAsync], see [with_config()](EventBusAsync::with_config()).
+pub type ConfigFactoryAsync = Box<dyn Fn() -> EventConfig + Send + Sync>;
+
+/// A **synchronous** registry of [Events](Event), one per type of payload. Publishers call
+/// [publish()](EventBus::publish()) with a value and every subscriber to its type is notified with it,
+/// so neither side needs a reference to a struct owning an [Event] field.
+/// # Examples
+/// ```
+/// struct OrderPlaced { order_id: u32 }
+///
+/// let mut bus = EventBus::new();
+/// bus.subscribe_as_fn(|event: &OrderPlaced| println!("Order {} placed...", event.order_id));
+///
+/// bus.publish(OrderPlaced { order_id: 42 });
+/// ```
+pub struct EventBus {
+    channels: HashMap<TypeId, Box<dyn Channel>>,
+    configure: ConfigFactory,
+}
+
+/// The **multi-threaded** counterpart of [EventBus], built on [EventAsync]. Every method takes `&self`, so the
+/// bus may be shared between threads in an `Arc`.
+///
+/// Events of the same type are published one at a time, while events of different types may be published
+/// concurrently. A subscriber must not subscribe to or publish its own type of event from inside `update`,
+/// since the event is locked while it is notified.
+pub struct EventBusAsync {
+    channels: RwLock<HashMap<TypeId, Arc<dyn ChannelAsync>>>,
+    configure: ConfigFactoryAsync,
+}
+
+/// An [Event] of the bus, with its payload type erased.
+trait Channel {
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn unsubscribe_if_present(&mut self, id: SubscriptionId) -> bool;
+}
+
+impl<E: 'static> Channel for Event<E> {
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn unsubscribe_if_present(&mut self, id: SubscriptionId) -> bool {
+        Event::unsubscribe_if_present(self, id)
+    }
+}
+
+/// An [EventAsync] of the bus, with its payload type erased.
+trait ChannelAsync: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+    fn unsubscribe_if_present(&self, id: SubscriptionId) -> bool;
+}
+
+impl<E: Send + Sync + 'static> ChannelAsync for Mutex<EventAsync<E>> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn unsubscribe_if_present(&self, id: SubscriptionId) -> bool {
+        self.lock().unwrap_or_else(PoisonError::into_inner).unsubscribe_if_present(id)
+    }
+}
+
+/// The configuration of the events of a bus unless configured otherwise: subscribers stay subscribed
+/// after a notification, since the bus is long-lived.
+fn default_config() -> EventConfig {
+    EventConfig { clear_subscribers_after_notification: Clear::None, ..Default::default() }
+}
+
+impl EventBus {
+    /// Creates an empty bus. Its events keep their subscribers after being notified, see [with_config()](EventBus::with_config()).
+    pub fn new() -> EventBus {
+        EventBus::with_config(default_config)
+    }
+
+    /// Creates an empty bus whose events are configured by `configure`, which is called once per type of
+    /// payload, the first time the type is subscribed to.
+    pub fn with_config<F>(configure: F) -> EventBus where F: Fn() -> EventConfig + 'static {
+        EventBus { channels: HashMap::new(), configure: Box::new(configure) }
+    }
+
+    /// Subscribe a [Subscriber] to the events of type `E`.
+    pub fn subscribe<E: 'static>(&mut self, subscriber: Rc<dyn Subscriber<E>>) -> SubscriptionId {
+        self.event_mut::<E>().subscribe(subscriber)
+    }
+
+    /// Subscribe a [SubscriberMut] to the events of type `E`.
+    pub fn subscribe_mut<E: 'static>(&mut self, subscriber: Rc<RefCell<dyn SubscriberMut<E>>>) -> SubscriptionId {
+        self.event_mut::<E>().subscribe_mut(subscriber)
+    }
+
+    /// Subscribe a [TrySubscriber] to the events of type `E`.
+    pub fn subscribe_try<E: 'static>(&mut self, subscriber: Rc<dyn TrySubscriber<E>>) -> SubscriptionId {
+        self.event_mut::<E>().subscribe_try(subscriber)
+    }
+
+    /// Subscribe a closure to the events of type `E`, which is usually inferred from the argument of the closure.
+    pub fn subscribe_as_fn<E: 'static, F>(&mut self, func: F) -> SubscriptionId where F: Fn(&E) + 'static {
+        self.event_mut::<E>().subscribe_as_fn_with(func)
+    }
+
+    /// Unsubscribe from this bus by the [SubscriptionId] returned when subscribing, whatever the type of event.
+    ///
+    /// # Panics
+    /// Panics if no subscriber of this bus was issued the id. See [try_unsubscribe()](EventBus::try_unsubscribe())
+    /// for a non-panicking alternative.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) {
+        if let Err(err) = self.try_unsubscribe(id) {
+            panic!("{err}");
+        }
+    }
+
+    /// Like [unsubscribe()](EventBus::unsubscribe()), but returns an error instead of panicking
+    /// when no subscriber of this bus was issued the id.
+    pub fn try_unsubscribe(&mut self, id: SubscriptionId) -> Result<(), RustventError> {
+        self.channels
+        .values_mut()
+        .any(|channel| channel.unsubscribe_if_present(id))
+        .then_some(())
+        .ok_or(RustventError::SubscriptionNotFound(id))
+    }
+
+    /// Notifies every subscriber to the events of type `E` with `event`. Publishing an event nobody
+    /// subscribed to does nothing.
+    pub fn publish<E: 'static>(&mut self, event: E) -> NotificationReport {
+        match self.channels.get_mut(&TypeId::of::<E>()) {
+            Some(channel) => downcast_mut::<E>(channel).notify_with(&event),
+            None => NotificationReport::default(),
+        }
+    }
+
+    /// The event carrying payloads of type `E`, if anything subscribed to it.
+    pub fn event<E: 'static>(&mut self) -> Option<&mut Event<E>> {
+        self.channels.get_mut(&TypeId::of::<E>()).map(downcast_mut::<E>)
+    }
+
+    fn event_mut<E: 'static>(&mut self) -> &mut Event<E> {
+        let channel = self.channels
+        .entry(TypeId::of::<E>())
+        .or_insert_with(|| Box::new(Event::<E>::new((self.configure)())));
+
+        downcast_mut::<E>(channel)
+    }
+}
+
+fn downcast_mut<E: 'static>(channel: &mut Box<dyn Channel>) -> &mut Event<E> {
+    channel
+    .as_any_mut()
+    .downcast_mut::<Event<E>>()
+    .expect("The channels of an EventBus are keyed by the TypeId of their payload.")
+}
+
+impl EventBusAsync {
+    /// Creates an empty bus. Its events keep their subscribers after being notified, see [with_config()](EventBusAsync::with_config()).
+    pub fn new() -> EventBusAsync {
+        EventBusAsync::with_config(default_config)
+    }
+
+    /// Creates an empty bus whose events are configured by `configure`, which is called once per type of
+    /// payload, the first time the type is subscribed to.
+    pub fn with_config<F>(configure: F) -> EventBusAsync where F: Fn() -> EventConfig + Send + Sync + 'static {
+        EventBusAsync { channels: RwLock::default(), configure: Box::new(configure) }
+    }
+
+    /// Subscribe a [SubscriberAsync] to the events of type `E`.
+    pub fn subscribe<E: Send + Sync + 'static>(&self, subscriber: Arc<dyn SubscriberAsync<E> + Send + Sync>) -> SubscriptionId {
+        self.with_event(|event: &mut EventAsync<E>| event.subscribe(subscriber))
+    }
+
+    /// Subscribe a [SubscriberAsyncMut] to the events of type `E`.
+    pub fn subscribe_mut<E: Send + Sync + 'static>(&self, subscriber: Arc<Mutex<dyn SubscriberAsyncMut<E> + Send + Sync>>) -> SubscriptionId {
+        self.with_event(|event: &mut EventAsync<E>| event.subscribe_mut(subscriber))
+    }
+
+    /// Subscribe a [TrySubscriberAsync] to the events of type `E`.
+    pub fn subscribe_try<E: Send + Sync + 'static>(&self, subscriber: Arc<dyn TrySubscriberAsync<E> + Send + Sync>) -> SubscriptionId {
+        self.with_event(|event: &mut EventAsync<E>| event.subscribe_try(subscriber))
+    }
+
+    /// Subscribe a closure to the events of type `E`, which is usually inferred from the argument of the closure.
+    pub fn subscribe_as_fn<E: Send + Sync + 'static, F>(&self, func: F) -> SubscriptionId where F: Fn(&E) + Send + Sync + 'static {
+        self.with_event(|event: &mut EventAsync<E>| event.subscribe_as_fn_with(func))
+    }
+
+    /// Unsubscribe from this bus by the [SubscriptionId] returned when subscribing, whatever the type of event.
+    ///
+    /// # Panics
+    /// Panics if no subscriber of this bus was issued the id. See [try_unsubscribe()](EventBusAsync::try_unsubscribe())
+    /// for a non-panicking alternative.
+    pub fn unsubscribe(&self, id: SubscriptionId) {
+        if let Err(err) = self.try_unsubscribe(id) {
+            panic!("{err}");
+        }
+    }
+
+    /// Like [unsubscribe()](EventBusAsync::unsubscribe()), but returns an error instead of panicking
+    /// when no subscriber of this bus was issued the id.
+    pub fn try_unsubscribe(&self, id: SubscriptionId) -> Result<(), RustventError> {
+        let channels: Vec<Arc<dyn ChannelAsync>> = self.channels.read().unwrap_or_else(PoisonError::into_inner).values().cloned().collect();
+
+        channels
+        .iter()
+        .any(|channel| channel.unsubscribe_if_present(id))
+        .then_some(())
+        .ok_or(RustventError::SubscriptionNotFound(id))
+    }
+
+    /// Notifies every subscriber to the events of type `E` with `event`, as configured by the
+    /// [Dispatch](crate::events::Dispatch) of the bus. Publishing an event nobody subscribed to does nothing.
+    pub fn publish<E: Send + Sync + 'static>(&self, event: E) -> NotificationReport {
+        let channel = self.channels.read().unwrap_or_else(PoisonError::into_inner).get(&TypeId::of::<E>()).cloned();
+
+        match channel {
+            Some(channel) => lock::<E>(&channel).notify_with(&event),
+            None => NotificationReport::default(),
+        }
+    }
+
+    /// Runs `f` on the event carrying payloads of type `E`, creating it if needed.
+    fn with_event<E: Send + Sync + 'static, R>(&self, f: impl FnOnce(&mut EventAsync<E>) -> R) -> R {
+        let channel = self.channels
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(TypeId::of::<E>())
+        .or_insert_with(|| Arc::new(Mutex::new(EventAsync::<E>::new((self.configure)()))))
+        .clone();
+
+        let result = f(&mut lock::<E>(&channel));
+        result
+    }
+}
+
+fn lock<E: Send + Sync + 'static>(channel: &Arc<dyn ChannelAsync>) -> std::sync::MutexGuard<'_, EventAsync<E>> {
+    channel
+    .as_any()
+    .downcast_ref::<Mutex<EventAsync<E>>>()
+    .expect("The channels of an EventBusAsync are keyed by the TypeId of their payload.")
+    .lock()
+    .unwrap_or_else(PoisonError::into_inner)
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for EventBusAsync {
+    fn default() -> Self {
+        Self::new()
+    }
+}
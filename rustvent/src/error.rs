@@ -0,0 +1,41 @@
+use std::{error::Error, fmt};
+
+use crate::subscription::SubscriptionId;
+
+/// The error returned by a [TrySubscriber](crate::subscriber::TrySubscriber) or
+/// [TrySubscriberAsync](crate::subscriber::TrySubscriberAsync). Any error type converts into
+/// it with the `?` operator.
+pub type SubscriberError = Box<dyn Error + Send + Sync>;
+
+/// Errors returned by the fallible methods of [Event](crate::events::Event) and
+/// [EventAsync](crate::event_async::EventAsync).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RustventError {
+    /// The subscriber passed to `try_unsubscribe` is not subscribed to the event.
+    SubscriberNotFound,
+    /// No subscriber of the event was issued this [SubscriptionId], or it was already unsubscribed.
+    SubscriptionNotFound(SubscriptionId),
+    /// A [ReentrantEvent](crate::reentrant::ReentrantEvent) was notified from one of its subscribers while
+    /// the given number of its notifications were already in progress.
+    MaxDepthExceeded(u32),
+    /// The mutable subscriber could not be notified, since an outer notification of the same
+    /// [ReentrantEvent](crate::reentrant::ReentrantEvent) is still notifying it.
+    AlreadyNotifying(SubscriptionId),
+}
+
+impl fmt::Display for RustventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RustventError::SubscriberNotFound => 
+                write!(f, "The provided 'subscriber' argument could not be found in the list of subscribers."),
+            RustventError::SubscriptionNotFound(id) => 
+                write!(f, "The provided 'subscription' argument could not be found in the list of subscribers ({id:?})."),
+            RustventError::MaxDepthExceeded(max_depth) => 
+                write!(f, "The event was notified recursively more than {max_depth} times."),
+            RustventError::AlreadyNotifying(id) => 
+                write!(f, "The subscriber {id:?} is already being notified by an outer notification of the event."),
+        }
+    }
+}
+
+impl Error for RustventError {}
@@ -1,6 +1,14 @@
-use std::{sync::{Arc, Mutex}, thread::{self, JoinHandle, ScopedJoinHandle}, ops::{AddAssign, SubAssign}};
-
-use crate::{subscriber::{SubscriberAsync, SubscriberAsyncMut}, events::{EventConfig, Notify, Clear}};
+use std::{collections::HashSet, sync::{Arc, Mutex, Weak}, thread, ops::{AddAssign, SubAssign}, panic::{self, AssertUnwindSafe}, time::{Duration, Instant}};
+
+use crate::{
+    subscriber::{SubscriberAsync, SubscriberAsyncMut, TrySubscriberAsync}, 
+    subscription::{remove_exhausted, Registered, SubscriptionGuard, SubscriptionId}, 
+    error::{RustventError, SubscriberError}, 
+    events::{EventConfig, Notify, Clear, PoisonPolicy, Dispatch}, 
+    report::NotificationReport,
+    trace,
+    stats::{EventStats, LatencyHistogram, StatsCounter}
+};
 
 macro_rules! default {
     () => {
@@ -8,175 +16,664 @@ macro_rules! default {
     };
 }
 
-#[derive(Default)]
-pub struct EventAsync {
-    pub times_subscribers_notified: u32,
-    pub times_func_subscribers_notified: u32,
-    pub times_subscribers_mut_notified: u32,
-    subscribers: Vec<Arc<(dyn SubscriberAsync + Send + Sync)>>,
-    subscribers_mut: Vec<Arc<Mutex<(dyn SubscriberAsyncMut + Send + Sync)>>>,
-    fn_subscribers: Vec<Arc<dyn Fn() + Send + Sync>>,
-    config: EventConfig
+/// A closure subscribed to an [EventAsync] through [subscribe_as_fn_with()](EventAsync::subscribe_as_fn_with()).
+pub type FnSubscriberAsync<T> = Arc<dyn Fn(&T) + Send + Sync>;
+
+/// Decides whether a subscriber of an [EventAsync] is notified, see [subscribe_filtered()](EventAsync::subscribe_filtered()).
+pub type PredicateAsync<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// The **multi-threaded** counterpart of [Event](crate::events::Event). By default every subscriber is
+/// notified on its own thread, with the payload `T` shared between them by reference. See
+/// [Dispatch](crate::events::Dispatch) to notify them on a [ThreadPool](crate::pool::ThreadPool) instead.
+pub struct EventAsync<T = ()> {
+    subscribers: Vec<Registered<Arc<dyn SubscriberAsync<T> + Send + Sync>, PredicateAsync<T>>>,
+    weak_subscribers: Vec<Registered<Weak<dyn SubscriberAsync<T> + Send + Sync>>>,
+    subscribers_mut: Vec<Registered<Arc<Mutex<dyn SubscriberAsyncMut<T> + Send + Sync>>>>,
+    fn_subscribers: Vec<Registered<FnSubscriberAsync<T>>>,
+    try_subscribers: Vec<Registered<Arc<dyn TrySubscriberAsync<T> + Send + Sync>>>,
+    config: EventConfig,
+    stats: StatsCounter
+}
+
+/// Identifies a subscription to an [EventAsync] that can be removed with [unsubscribe()](EventAsync::unsubscribe()).
+/// Any [SubscriptionId] returned by the event, or the [SubscriberAsync] that was subscribed, converts into one.
+pub enum SubscriptionAsync<T = ()> {
+    Id(SubscriptionId),
+    Subscriber(Arc<dyn SubscriberAsync<T> + Send + Sync>),
 }
 
-impl EventAsync {
-    pub fn new(config: EventConfig) -> EventAsync {
+impl<T> EventAsync<T> {
+    pub fn new(config: EventConfig) -> EventAsync<T> {
         EventAsync 
         { 
             subscribers: default!(), 
+            weak_subscribers: default!(),
             subscribers_mut: default!(),
             fn_subscribers: default!(),
-            times_subscribers_notified: default!(), 
-            times_func_subscribers_notified: default!(),
-            times_subscribers_mut_notified: default!(),
-            config
+            try_subscribers: default!(),
+            config,
+            stats: default!()
         }
     }
 
-    pub fn get_subscribers(&self) -> &Vec<Arc<(dyn SubscriberAsync + Send + Sync)>> {
-        &self.subscribers
+    pub fn get_subscribers(&self) -> Vec<Arc<dyn SubscriberAsync<T> + Send + Sync>> {
+        self.subscribers
+        .iter()
+        .filter(|sub| sub.is_active())
+        .map(|sub| sub.subscriber.clone())
+        .chain(self.weak_subscribers.iter().filter_map(|sub| sub.subscriber.upgrade()))
+        .collect()
     }
 
-    pub fn get_fn_subscribers(&self) -> &Vec<Arc<dyn Fn() + Send + Sync>> {
-        &self.fn_subscribers
+    pub fn get_subscribers_mut(&self) -> Vec<Arc<Mutex<dyn SubscriberAsyncMut<T> + Send + Sync>>> {
+        self.subscribers_mut
+        .iter()
+        .filter(|sub| sub.is_active())
+        .map(|sub| sub.subscriber.clone())
+        .collect()
     }
 
-    pub fn subscribe(&mut self, subscriber: Arc<(dyn SubscriberAsync + Send + Sync)>) {
-        self.subscribers.push(subscriber);
+    /// Get all [Fallible Subscribers](TrySubscriberAsync) listening to this event.
+    pub fn get_try_subscribers(&self) -> Vec<Arc<dyn TrySubscriberAsync<T> + Send + Sync>> {
+        self.try_subscribers
+        .iter()
+        .filter(|sub| sub.is_active())
+        .map(|sub| sub.subscriber.clone())
+        .collect()
     }
 
-    pub fn subscribe_mut(&mut self, subscriber: Arc<Mutex<(dyn SubscriberAsyncMut + Send + Sync)>>) {
-        self.subscribers_mut.push(subscriber);
+    pub fn get_fn_subscribers(&self) -> Vec<FnSubscriberAsync<T>> {
+        self.fn_subscribers
+        .iter()
+        .filter(|func| func.is_active())
+        .map(|func| func.subscriber.clone())
+        .collect()
     }
 
-    pub fn subscribe_as_fn<F>(&mut self, subscriber: F) where F: Fn() + Send + Sync + 'static {
-        self.fn_subscribers.push(Arc::new(subscriber));
+    pub fn subscribe(&mut self, subscriber: Arc<dyn SubscriberAsync<T> + Send + Sync>) -> SubscriptionId {
+        let registered = Registered::new(subscriber);
+        let id = registered.id;
+        self.subscribers.push(registered);
+        self.subscribed(id, "subscriber")
     }
 
-    pub fn unsubscribe(&mut self, subscriber: Arc<(dyn SubscriberAsync + Send + Sync)>) {
-        let index = self.subscribers
-        .iter()
-        .position(|sub| Arc::ptr_eq(&subscriber, sub))
-        .expect("The provided 'subscriber' argument could not be found in the list of subscribers.");
-        
-        self.subscribers.swap_remove(index);
+    pub fn subscribe_mut(&mut self, subscriber: Arc<Mutex<dyn SubscriberAsyncMut<T> + Send + Sync>>) -> SubscriptionId {
+        let registered = Registered::new(subscriber);
+        let id = registered.id;
+        self.subscribers_mut.push(registered);
+        self.subscribed(id, "subscriber_mut")
+    }
+
+    pub fn subscribe_as_fn_with<F>(&mut self, subscriber: F) -> SubscriptionId where F: Fn(&T) + Send + Sync + 'static {
+        let func: FnSubscriberAsync<T> = Arc::new(subscriber);
+        let registered = Registered::new(func);
+        let id = registered.id;
+        self.fn_subscribers.push(registered);
+        self.subscribed(id, "fn")
+    }
+
+    /// Allows any implementors of the [TrySubscriberAsync] Trait to subscribe to this event. Errors returned by
+    /// the subscriber are handled according to the [ErrorPolicy](crate::events::ErrorPolicy) of this event.
+    pub fn subscribe_try(&mut self, subscriber: Arc<dyn TrySubscriberAsync<T> + Send + Sync>) -> SubscriptionId {
+        let registered = Registered::new(subscriber);
+        let id = registered.id;
+        self.try_subscribers.push(registered);
+        self.subscribed(id, "try")
+    }
+
+    /// Subscribe a [SubscriberAsync] for as long as the returned [SubscriptionGuard] is alive.
+    /// The guard is `Send`, so it may be dropped from any thread.
+    pub fn subscribe_scoped(&mut self, subscriber: Arc<dyn SubscriberAsync<T> + Send + Sync>) -> SubscriptionGuard {
+        let (registered, guard) = Registered::scoped(subscriber);
+        self.subscribers.push(registered);
+        self.subscribed(guard.id(), "subscriber");
+        guard
+    }
+
+    /// Subscribe a [SubscriberAsyncMut] for as long as the returned [SubscriptionGuard] is alive.
+    pub fn subscribe_mut_scoped(&mut self, subscriber: Arc<Mutex<dyn SubscriberAsyncMut<T> + Send + Sync>>) -> SubscriptionGuard {
+        let (registered, guard) = Registered::scoped(subscriber);
+        self.subscribers_mut.push(registered);
+        self.subscribed(guard.id(), "subscriber_mut");
+        guard
+    }
+
+    /// Subscribe a closure for as long as the returned [SubscriptionGuard] is alive.
+    pub fn subscribe_as_fn_scoped<F>(&mut self, subscriber: F) -> SubscriptionGuard where F: Fn(&T) + Send + Sync + 'static {
+        let func: FnSubscriberAsync<T> = Arc::new(subscriber);
+        let (registered, guard) = Registered::scoped(func);
+        self.fn_subscribers.push(registered);
+        self.subscribed(guard.id(), "fn");
+        guard
+    }
+
+    /// Subscribe a [SubscriberAsync] that is only notified when `predicate` returns `true` for the payload of
+    /// the notification. Predicates are evaluated on the thread calling `notify`, before subscribers are dispatched.
+    pub fn subscribe_filtered<F>(&mut self, subscriber: Arc<dyn SubscriberAsync<T> + Send + Sync>, predicate: F) -> SubscriptionId
+    where F: Fn(&T) -> bool + Send + Sync + 'static {
+        let predicate: PredicateAsync<T> = Box::new(predicate);
+        let registered = Registered::new(subscriber).with_filter(predicate);
+        let id = registered.id;
+        self.subscribers.push(registered);
+        self.subscribed(id, "subscriber")
+    }
+
+    /// Subscribe a [SubscriberAsync] without keeping it alive. Once every `Arc` to the subscriber is dropped it
+    /// is no longer notified, and the event prunes it on its next notification, counting it in
+    /// [EventStats::dead_subscribers_pruned].
+    pub fn subscribe_weak(&mut self, subscriber: Weak<dyn SubscriberAsync<T> + Send + Sync>) -> SubscriptionId {
+        let registered = Registered::new(subscriber);
+        let id = registered.id;
+        self.weak_subscribers.push(registered);
+        self.subscribed(id, "weak")
+    }
+
+    /// Subscribe a [SubscriberAsync] that is unsubscribed after its first notification, while the other
+    /// subscribers of this event stay subscribed.
+    pub fn subscribe_once(&mut self, subscriber: Arc<dyn SubscriberAsync<T> + Send + Sync>) -> SubscriptionId {
+        self.subscribe_n(subscriber, 1)
+    }
+
+    /// Subscribe a [SubscriberAsync] that is unsubscribed after `times` notifications.
+    ///
+    /// # Panics
+    /// Panics if `times` is zero.
+    pub fn subscribe_n(&mut self, subscriber: Arc<dyn SubscriberAsync<T> + Send + Sync>, times: u32) -> SubscriptionId {
+        let registered = Registered::new(subscriber).with_limit(times);
+        let id = registered.id;
+        self.subscribers.push(registered);
+        self.subscribed(id, "subscriber")
+    }
+
+    /// Subscribe a closure that is unsubscribed after its first notification.
+    pub fn subscribe_as_fn_once<F>(&mut self, subscriber: F) -> SubscriptionId where F: Fn(&T) + Send + Sync + 'static {
+        self.subscribe_as_fn_n(subscriber, 1)
+    }
+
+    /// Subscribe a closure that is unsubscribed after `times` notifications.
+    ///
+    /// # Panics
+    /// Panics if `times` is zero.
+    pub fn subscribe_as_fn_n<F>(&mut self, subscriber: F, times: u32) -> SubscriptionId where F: Fn(&T) + Send + Sync + 'static {
+        let func: FnSubscriberAsync<T> = Arc::new(subscriber);
+        let registered = Registered::new(func).with_limit(times);
+        let id = registered.id;
+        self.fn_subscribers.push(registered);
+        self.subscribed(id, "fn")
+    }
+
+    /// Unsubscribe from this event, either by the [SubscriptionId] returned when subscribing or by the
+    /// [SubscriberAsync] itself. Unsubscribing by id works for every kind of subscriber, including closures.
+    ///
+    /// # Panics
+    /// Panics if the subscriber is not subscribed to this event. See [try_unsubscribe()](EventAsync::try_unsubscribe())
+    /// and [unsubscribe_if_present()](EventAsync::unsubscribe_if_present()) for non-panicking alternatives.
+    pub fn unsubscribe(&mut self, subscription: impl Into<SubscriptionAsync<T>>) {
+        if let Err(err) = self.try_unsubscribe(subscription) {
+            panic!("{err}");
+        }
+    }
+
+    /// # Panics
+    /// Panics if the subscriber is not subscribed to this event. See [try_unsubscribe_mut()](EventAsync::try_unsubscribe_mut())
+    /// for a non-panicking alternative.
+    pub fn unsubscribe_mut(&mut self, subscriber: Arc<Mutex<dyn SubscriberAsyncMut<T> + Send + Sync>>) {
+        if let Err(err) = self.try_unsubscribe_mut(subscriber) {
+            panic!("{err}");
+        }
+    }
+
+    /// Like [unsubscribe()](EventAsync::unsubscribe()), but returns an error instead of panicking
+    /// when the subscriber is not subscribed to this event.
+    pub fn try_unsubscribe(&mut self, subscription: impl Into<SubscriptionAsync<T>>) -> Result<(), RustventError> {
+        self.remove_cancelled();
+
+        let id = match subscription.into() {
+            SubscriptionAsync::Id(id) => {
+                self.unsubscribe_id(id)?;
+                id
+            },
+            SubscriptionAsync::Subscriber(subscriber) => {
+                if let Some(index) = self.subscribers.iter().position(|sub| Arc::ptr_eq(&subscriber, &sub.subscriber)) {
+                    self.subscribers.remove(index).id
+                } else {
+                    let index = self.weak_subscribers
+                    .iter()
+                    .position(|sub| Weak::ptr_eq(&sub.subscriber, &Arc::downgrade(&subscriber)))
+                    .ok_or(RustventError::SubscriberNotFound)?;
+
+                    self.weak_subscribers.remove(index).id
+                }
+            }
+        };
+
+        self.unsubscribed(id);
+        Ok(())
     }
 
-    pub fn unsubscribe_mut(&mut self, subscriber: Arc<Mutex<(dyn SubscriberAsyncMut + Send + Sync)>>) {
+    /// Like [unsubscribe_mut()](EventAsync::unsubscribe_mut()), but returns an error instead of panicking
+    /// when the subscriber is not subscribed to this event.
+    pub fn try_unsubscribe_mut(&mut self, subscriber: Arc<Mutex<dyn SubscriberAsyncMut<T> + Send + Sync>>) -> Result<(), RustventError> {
+        self.remove_cancelled();
+
         let index = self.subscribers_mut
         .iter()
-        .position(|sub| Arc::ptr_eq(&subscriber, sub))
-        .expect("The provided 'subscriber' argument could not be found in the list of subscribers.");
+        .position(|sub| Arc::ptr_eq(&subscriber, &sub.subscriber))
+        .ok_or(RustventError::SubscriberNotFound)?;
         
-        self.subscribers.swap_remove(index);
+        let id = self.subscribers_mut.remove(index).id;
+        self.unsubscribed(id);
+        Ok(())
     }
 
-    pub fn notify(&mut self) {
-        match self.config.subscribers_to_notify {
-            Notify::All => {
-                self.notify_subscribers();
-                self.notify_fn_subscribers();
-            },
-            Notify::OnlySubscribers => self.notify_subscribers(),
-            Notify::OnlyFnSubscribers => self.notify_fn_subscribers(),
-            _ => ()
-        }
+    /// Removes the subscriber if it is still subscribed to this event, and does nothing otherwise,
+    /// e.g. because it was already removed by [Clear] after a notification.
+    /// Returns whether a subscriber was removed.
+    pub fn unsubscribe_if_present(&mut self, subscription: impl Into<SubscriptionAsync<T>>) -> bool {
+        self.try_unsubscribe(subscription).is_ok()
+    }
 
-        self.try_clear();
+    /// The name of this event, see [EventConfig::name].
+    pub fn name(&self) -> &'static str {
+        self.config.name.unwrap_or(std::any::type_name::<T>())
     }
 
-    pub fn notify_subscribers(&mut self) {
-        let mut handles: Vec<JoinHandle<()>> = Vec::new();
+    /// Returns `id`, once the subscription of a subscriber of `kind` was traced.
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn subscribed(&self, id: SubscriptionId, kind: &'static str) -> SubscriptionId {
+        trace_event!(debug, event = self.name(), subscriber = ?id, kind, "subscribed");
+        id
+    }
 
-        for sub in self.subscribers.iter() {
-            let sub_clone = sub.clone();
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn unsubscribed(&self, id: SubscriptionId) {
+        trace_event!(debug, event = self.name(), subscriber = ?id, "unsubscribed");
+    }
 
-            let handle = thread::spawn(move || {
-                sub_clone.update();
-            });
+    /// Drops subscribers whose [SubscriptionGuard] has been dropped.
+    fn remove_cancelled(&mut self) {
+        self.subscribers.retain(|sub| sub.is_active());
+        self.subscribers_mut.retain(|sub| sub.is_active());
+        self.fn_subscribers.retain(|func| func.is_active());
+        self.try_subscribers.retain(|sub| sub.is_active());
+    }
 
-            handles.push(handle);
+    /// Drops [weak subscribers](EventAsync::subscribe_weak()) whose subscriber has been dropped.
+    fn remove_dead(&mut self) {
+        let before = self.weak_subscribers.len();
+        self.weak_subscribers.retain(|sub| sub.subscriber.strong_count() > 0);
+        self.stats.record_pruned(before - self.weak_subscribers.len());
+    }
+
+    fn unsubscribe_id(&mut self, id: SubscriptionId) -> Result<(), RustventError> {
+        if let Some(index) = self.subscribers.iter().position(|sub| sub.id == id) {
+            self.subscribers.remove(index);
+        } else if let Some(index) = self.subscribers_mut.iter().position(|sub| sub.id == id) {
+            self.subscribers_mut.remove(index);
+        } else if let Some(index) = self.fn_subscribers.iter().position(|func| func.id == id) {
+            self.fn_subscribers.remove(index);
+        } else if let Some(index) = self.try_subscribers.iter().position(|sub| sub.id == id) {
+            self.try_subscribers.remove(index);
+        } else if let Some(index) = self.weak_subscribers.iter().position(|sub| sub.id == id) {
+            self.weak_subscribers.remove(index);
+        } else {
+            return Err(RustventError::SubscriptionNotFound(id));
         }
 
-        self.times_subscribers_notified += 1;
+        Ok(())
+    }
 
-        handles.into_iter().for_each(|h| h.join().unwrap())
+    /// A snapshot of the statistics of this event. They are kept in atomics, so reading them never
+    /// waits for a notification in progress on another thread.
+    pub fn stats(&self) -> EventStats {
+        self.stats.snapshot()
     }
 
-    pub fn notify_fn_subscribers(&mut self) {
-        let mut handles: Vec<JoinHandle<()>> = Vec::new();
+    /// Sets every statistic of this event back to zero and drops the latency histograms of its subscribers.
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
 
-        for closure in self.fn_subscribers.iter() {
-            let closure_clone = closure.clone();
+    /// How long the invocations of the subscriber `id` took, see [Event::latency()](crate::events::Event::latency()).
+    /// Every invocation is timed on the thread running it.
+    pub fn latency(&self, id: SubscriptionId) -> Option<LatencyHistogram> {
+        self.stats.latency(id)
+    }
 
-            let handle = thread::spawn(move || {
-                closure_clone();
-            });
+    /// The latency histograms of the notified subscribers, see [Event::latencies()](crate::events::Event::latencies()).
+    pub fn latencies(&self) -> Vec<(SubscriptionId, LatencyHistogram)> {
+        self.stats.latencies_snapshot()
+    }
 
-            handles.push(handle);
+    /// Records how long each notified subscriber took if the event is configured to, handing the slow ones to the
+    /// [SlowHandlerPolicy](crate::events::SlowHandlerPolicy) of this event.
+    fn record_latencies<R>(&self, results: &[Timed<R>], report: &mut NotificationReport) {
+        for (id, elapsed, _) in results {
+            trace_event!(trace, subscriber = ?id, ?elapsed, "subscriber notified");
+            if self.config.record_latency { self.stats.record_latency(*id, *elapsed); }
+            self.config.check_latency(report, *id, *elapsed);
         }
+    }
+
+    /// Drops the latency histograms of subscribers that were unsubscribed since the previous notification.
+    fn prune_latencies(&self) {
+        if !self.config.record_latency { return; }
 
-        self.times_func_subscribers_notified += 1;
+        let subscribed = self.subscribers.iter().map(|sub| sub.id)
+        .chain(self.weak_subscribers.iter().map(|sub| sub.id))
+        .chain(self.subscribers_mut.iter().map(|sub| sub.id))
+        .chain(self.fn_subscribers.iter().map(|func| func.id))
+        .chain(self.try_subscribers.iter().map(|sub| sub.id));
+        let len = self.subscribers.len() + self.weak_subscribers.len() + self.subscribers_mut.len() + self.fn_subscribers.len() + self.try_subscribers.len();
 
-        handles.into_iter().for_each(|h| h.join().unwrap())
+        self.stats.prune_latencies(len, subscribed);
     }
-    
-    pub fn notify_subscribers_mut(&mut self) {
 
-        thread::scope(|s| {
-            let mut handles: Vec<ScopedJoinHandle<()>> = Vec::new();
+    /// Unsubscribes every subscriber in `selection`, as the event does after each notification
+    /// according to its [EventConfig].
+    pub fn clear(&mut self, selection: Clear) {
+        if !selection.is_empty() {
+            trace_event!(debug, event = self.name(), ?selection, "cleared");
+        }
 
-            for sub in self.subscribers_mut.iter() {
-                let sub_clone = sub.clone();
+        if selection.contains(Clear::SUBSCRIBERS) {
+            self.subscribers.clear();
+            self.weak_subscribers.clear();
+            self.try_subscribers.clear();
+        }
+        if selection.contains(Clear::SUBSCRIBERS_MUT) {
+            self.subscribers_mut.clear();
+        }
+        if selection.contains(Clear::FN_SUBSCRIBERS) {
+            self.fn_subscribers.clear();
+        }
+    }
+}
 
-                let handle = s.spawn(move || {
-                    let mut val = sub_clone.lock().unwrap();
-                    val.update_mut();
-                });
+impl<T: Sync> EventAsync<T> {
+    /// Notifies subscribers, handing each of them a reference to `args` on the threads chosen by the
+    /// [Dispatch](crate::events::Dispatch) of this event. Returns once every subscriber has been notified, with a [NotificationReport] listing the
+    /// [TrySubscribers](TrySubscriberAsync) that failed and the subscribers that panicked. A panicking
+    /// subscriber does not prevent the others from being notified.
+    ///
+    /// Subscribers run in parallel, so with [ErrorPolicy::StopAtFirst](crate::events::ErrorPolicy::StopAtFirst)
+    /// subscribers that were already running complete and their panics are reported, but only the first
+    /// error is reported and the remaining kinds of subscribers are not notified.
+    pub fn notify_with(&mut self, args: &T) -> NotificationReport {
+        self.notify_selected_with(args, self.config.subscribers_to_notify)
+    }
 
-                handles.push(handle);
+    /// Like [notify_with()](EventAsync::notify_with()), but only notifies the subscribers in `selection`
+    /// instead of those selected by the [EventConfig] of this event.
+    pub fn notify_selected_with(&mut self, args: &T, selection: Notify) -> NotificationReport {
+        let _span = trace_span!(DEBUG, "notify", event = self.name()).entered();
+        trace_event!(debug, ?selection, "notify started");
+
+        self.remove_cancelled();
+        self.remove_dead();
+        self.prune_latencies();
+        let mut report = NotificationReport::default();
+
+        let proceed = !selection.contains(Notify::SUBSCRIBERS) || self.dispatch_subscribers(args, &mut report);
+        if proceed {
+            if selection.contains(Notify::SUBSCRIBERS_MUT) {
+                self.dispatch_subscribers_mut(args, &mut report);
             }
+            if selection.contains(Notify::FN_SUBSCRIBERS) {
+                self.dispatch_fn_subscribers(args, &mut report);
+            }
+        }
 
-            self.times_subscribers_mut_notified += 1;
+        self.clear(self.config.clear_subscribers_after_notification);
+        self.stats.record_notification(&report);
+        trace::report(&report);
+        trace_event!(debug, ok = report.is_ok(), "notify completed");
+        report
+    }
 
-            handles.into_iter().for_each(|h| h.join().unwrap())
-        });        
+    /// Notifies the [SubscriberAsyncs](SubscriberAsync) and [TrySubscriberAsyncs](TrySubscriberAsync) together.
+    /// Returns `false` if a failing subscriber stopped the notification.
+    fn dispatch_subscribers(&mut self, args: &T, report: &mut NotificationReport) -> bool {
+        let mut jobs: Vec<(SubscriptionId, Job<'_, Result<(), SubscriberError>>)> = Vec::new();
 
+        for sub in self.subscribers.iter().filter(|sub| sub.is_active() && sub.accepts(args)) {
+            jobs.push((sub.id, Box::new(move || {
+                sub.subscriber.update_with(args);
+                Ok(())
+            })));
+        }
+
+        for (id, sub) in self.weak_subscribers.iter().filter_map(|sub| Some((sub.id, sub.subscriber.upgrade()?))) {
+            jobs.push((id, Box::new(move || {
+                sub.update_with(args);
+                Ok(())
+            })));
+        }
+
+        for sub in self.try_subscribers.iter().filter(|sub| sub.is_active()) {
+            jobs.push((sub.id, Box::new(move || sub.subscriber.try_update_with(args))));
+        }
+
+        if jobs.is_empty() { return true; }
+
+        let results = run_jobs(&self.config.dispatch, jobs);
+        self.stats.record_invocations(results.len());
+        self.stats.record_subscribers_notified();
+
+        self.record_latencies(&results, report);
+
+        let notified: HashSet<SubscriptionId> = results.iter().map(|(id, _, _)| *id).collect();
+        remove_exhausted(&mut self.subscribers, &notified);
+        remove_exhausted(&mut self.weak_subscribers, &notified);
+        remove_exhausted(&mut self.try_subscribers, &notified);
+
+        // Every job already ran, so the panics are reported even once a failing subscriber stopped the notification.
+        let mut proceed = true;
+        for (id, _, result) in results {
+            match result {
+                Ok(Ok(())) => (),
+                Ok(Err(error)) if proceed => proceed = self.config.on_subscriber_error.handle(report, id, error),
+                Ok(Err(_)) => report.push_ignored_failure(),
+                Err(payload) => report.push_panic(id, payload),
+            }
+        }
+
+        proceed
     }
 
-    fn try_clear(&mut self) {
-        match self.config.clear_subscribers_after_notification {
-            Clear::All => self.clear_all_subscribers(),
-            Clear::OnlySubscribers => self.clear_subscribers(),
-            Clear::OnlyFuncSubscribers => self.clear_fn_subscribers(),
-            Clear::None => return,
-            _ => ()
+    fn dispatch_fn_subscribers(&mut self, args: &T, report: &mut NotificationReport) {
+        let mut jobs: Vec<(SubscriptionId, Job<'_, ()>)> = Vec::new();
+
+        for closure in self.fn_subscribers.iter().filter(|func| func.is_active()) {
+            jobs.push((closure.id, Box::new(move || (closure.subscriber)(args))));
         }
+
+        if jobs.is_empty() { return; }
+
+        let results = run_jobs(&self.config.dispatch, jobs);
+        self.stats.record_invocations(results.len());
+        self.stats.record_fn_subscribers_notified();
+
+        self.record_latencies(&results, report);
+
+        let notified: HashSet<SubscriptionId> = results.iter().map(|(id, _, _)| *id).collect();
+        remove_exhausted(&mut self.fn_subscribers, &notified);
+
+        for (id, _, result) in results {
+            if let Err(payload) = result {
+                report.push_panic(id, payload);
+            }
+        }
+    }
+
+    fn dispatch_subscribers_mut(&mut self, args: &T, report: &mut NotificationReport) {
+        let recover = matches!(self.config.on_poisoned_subscriber, PoisonPolicy::Recover);
+        let mut jobs: Vec<(SubscriptionId, Job<'_, bool>)> = Vec::new();
+
+        for sub in self.subscribers_mut.iter().filter(|sub| sub.is_active()) {
+            // Returns `false` if the subscriber was not notified because its Mutex is poisoned.
+            jobs.push((sub.id, Box::new(move || {
+                let mut val = match sub.subscriber.lock() {
+                    Ok(val) => val,
+                    Err(poisoned) if recover => {
+                        sub.subscriber.clear_poison();
+                        poisoned.into_inner()
+                    },
+                    Err(_) => return false,
+                };
+                val.update_mut_with(args);
+                true
+            })));
+        }
+
+        if jobs.is_empty() { return; }
+
+        let results = run_jobs(&self.config.dispatch, jobs);
+        self.stats.record_invocations(results.len());
+        self.stats.record_subscribers_mut_notified();
+
+        self.record_latencies(&results, report);
+
+        let notified: HashSet<SubscriptionId> = results.iter().map(|(id, _, _)| *id).collect();
+        remove_exhausted(&mut self.subscribers_mut, &notified);
+
+        for (id, _, result) in results {
+            match result {
+                Ok(true) => (),
+                Ok(false) => {
+                    if let PoisonPolicy::Propagate = self.config.on_poisoned_subscriber {
+                        panic!("The subscriber {id:?} could not be notified because its Mutex is poisoned.");
+                    }
+
+                    self.subscribers_mut.retain(|sub| sub.id != id);
+                    report.push_evicted(id);
+                },
+                Err(payload) => report.push_panic(id, payload),
+            }
+        }
+    }
+}
+
+impl EventAsync<()> {
+    pub fn subscribe_as_fn<F>(&mut self, subscriber: F) -> SubscriptionId where F: Fn() + Send + Sync + 'static {
+        self.subscribe_as_fn_with(move |_| subscriber())
+    }
+
+    pub fn notify(&mut self) -> NotificationReport {
+        self.notify_with(&())
+    }
+
+    pub fn notify_selected(&mut self, selection: Notify) -> NotificationReport {
+        self.notify_selected_with(&(), selection)
     }
 
-    fn clear_all_subscribers(&mut self) {
-        self.clear_subscribers();
-        self.clear_fn_subscribers();
+    pub fn notify_subscribers(&mut self) -> NotificationReport {
+        let mut report = NotificationReport::default();
+        self.dispatch_subscribers(&(), &mut report);
+        report
     }
 
-    fn clear_subscribers(&mut self) {
-        self.subscribers.clear();
+    pub fn notify_fn_subscribers(&mut self) -> NotificationReport {
+        let mut report = NotificationReport::default();
+        self.dispatch_fn_subscribers(&(), &mut report);
+        report
     }
     
-    fn clear_fn_subscribers(&mut self) {
-        self.fn_subscribers.clear();
+    pub fn notify_subscribers_mut(&mut self) -> NotificationReport {
+        let mut report = NotificationReport::default();
+        self.dispatch_subscribers_mut(&(), &mut report);
+        report
     }
 }
 
-impl AddAssign<Arc<(dyn SubscriberAsync + Send + Sync)>> for EventAsync {
-    fn add_assign(&mut self, rhs: Arc<(dyn SubscriberAsync + Send + Sync)>) {
+/// The notification of a single subscriber, borrowing the subscriber and the payload from the event.
+type Job<'env, R> = Box<dyn FnOnce() -> R + Send + 'env>;
+
+/// The outcome of a [Job]: the subscriber it notified, how long it ran and what it returned.
+type Timed<R> = (SubscriptionId, Duration, thread::Result<R>);
+
+/// A [Job] timing itself and catching its own panic.
+type TimedJob<'env, R> = Job<'env, (Duration, thread::Result<R>)>;
+
+/// Runs every job as configured by `dispatch`, keeping the panic payload of the jobs that panicked
+/// instead of propagating it. Results are returned in the order of `jobs`.
+fn run_jobs<R: Send + 'static>(dispatch: &Dispatch, jobs: Vec<(SubscriptionId, Job<'_, R>)>) -> Vec<Timed<R>> {
+    let (ids, jobs): (Vec<SubscriptionId>, Vec<TimedJob<'_, R>>) = jobs
+    .into_iter()
+    .map(|(id, job)| -> (SubscriptionId, TimedJob<'_, R>) {
+        // Created on the notifying thread, so the span of the notification is its parent.
+        let span = trace_span!(TRACE, "subscriber", subscriber = ?id);
+
+        (id, Box::new(move || {
+            let _span = span.entered();
+            let started = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(job));
+            (started.elapsed(), result)
+        }))
+    })
+    .unzip();
+
+    let results: Vec<thread::Result<(Duration, thread::Result<R>)>> = match dispatch {
+        Dispatch::ThreadPerSubscriber => thread::scope(|s| {
+            let handles: Vec<_> = jobs.into_iter().map(|job| s.spawn(job)).collect();
+            handles.into_iter().map(|handle| handle.join()).collect()
+        }),
+        Dispatch::Pool(pool) => pool.run_all(jobs),
+        Dispatch::Sequential => jobs
+        .into_iter()
+        .map(|job| panic::catch_unwind(AssertUnwindSafe(job)))
+        .collect(),
+    };
+
+    ids
+    .into_iter()
+    .zip(results)
+    .map(|(id, result)| {
+        let (elapsed, result) = result.unwrap_or_else(|payload| (Duration::ZERO, Err(payload)));
+        (id, elapsed, result)
+    })
+    .collect()
+}
+
+impl<T> Default for EventAsync<T> {
+    fn default() -> Self {
+        Self::new(EventConfig::default())
+    }
+}
+
+impl<T> From<SubscriptionId> for SubscriptionAsync<T> {
+    fn from(id: SubscriptionId) -> Self {
+        SubscriptionAsync::Id(id)
+    }
+}
+
+impl<T> From<Arc<dyn SubscriberAsync<T> + Send + Sync>> for SubscriptionAsync<T> {
+    fn from(subscriber: Arc<dyn SubscriberAsync<T> + Send + Sync>) -> Self {
+        SubscriptionAsync::Subscriber(subscriber)
+    }
+}
+
+impl<T, S> From<Arc<S>> for SubscriptionAsync<T> where S: SubscriberAsync<T> + Send + Sync + 'static {
+    fn from(subscriber: Arc<S>) -> Self {
+        SubscriptionAsync::Subscriber(subscriber)
+    }
+}
+
+impl<T> AddAssign<Arc<dyn SubscriberAsync<T> + Send + Sync>> for EventAsync<T> {
+    fn add_assign(&mut self, rhs: Arc<dyn SubscriberAsync<T> + Send + Sync>) {
         self.subscribe(rhs);
     }
 }
     
-impl SubAssign<Arc<(dyn SubscriberAsync + Send + Sync)>> for EventAsync {
-    fn sub_assign(&mut self, rhs: Arc<(dyn SubscriberAsync + Send + Sync)>) {
-        self.unsubscribe(rhs);
+impl<T> SubAssign<Arc<dyn SubscriberAsync<T> + Send + Sync>> for EventAsync<T> {
+    fn sub_assign(&mut self, rhs: Arc<dyn SubscriberAsync<T> + Send + Sync>) {
+        self.unsubscribe_if_present(rhs);
+    }
+}
+
+impl<T> SubAssign<SubscriptionId> for EventAsync<T> {
+    fn sub_assign(&mut self, rhs: SubscriptionId) {
+        self.unsubscribe_if_present(rhs);
     }
 }
 
@@ -233,7 +730,7 @@ mod tests {
         let test_sub = TestSubscriber::default().into_arc();
 
         event += test_sub.clone();
-        event.clear_subscribers();
+        event.clear(Clear::SUBSCRIBERS);
 
         assert!(event.get_subscribers().is_empty());
     }
@@ -243,7 +740,7 @@ mod tests {
         let mut event = EventAsync::default();
 
         event.subscribe_as_fn(|| println!("Closure notified..."));
-        event.clear_fn_subscribers();
+        event.clear(Clear::FN_SUBSCRIBERS);
 
         assert!(event.get_fn_subscribers().is_empty());
     }
@@ -256,10 +753,63 @@ mod tests {
 
         event.subscribe_as_fn(|| println!("Closure notified..."));
         event.subscribe(test_sub);
-        event.clear_all_subscribers();
+        event.clear(Clear::All);
 
         assert!(event.get_subscribers().is_empty());
         assert!(event.get_fn_subscribers().is_empty());
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn event_async_fn_subscriber_can_unsubscribe_by_id() {
+        let mut event = EventAsync::default();
+
+        let id = event.subscribe_as_fn(|| println!("Closure notified..."));
+        event.unsubscribe(id);
+
+        assert!(event.get_fn_subscribers().is_empty());
+    }
+
+    #[test]
+    fn event_async_sub_assign_removes_subscription_id() {
+        let mut event = EventAsync::default();
+
+        let id = event.subscribe(TestSubscriber::default().into_arc());
+        event -= id;
+
+        assert!(event.get_subscribers().is_empty());
+    }
+
+    #[test]
+    fn event_async_scoped_subscriber_guard_can_be_dropped_on_another_thread() {
+        let mut event = EventAsync::default();
+
+        let guard = event.subscribe_scoped(TestSubscriber::default().into_arc());
+        assert_eq!(1, event.get_subscribers().len());
+
+        thread::spawn(move || drop(guard)).join().unwrap();
+
+        assert!(event.get_subscribers().is_empty());
+    }
+
+    #[test]
+    fn event_async_try_unsubscribe_returns_error_if_type_did_not_subscribe() {
+        let mut event = EventAsync::default();
+
+        let test_sub = TestSubscriber::default().into_arc();
+
+        assert_eq!(Err(RustventError::SubscriberNotFound), event.try_unsubscribe(test_sub.clone()));
+        assert!(!event.unsubscribe_if_present(test_sub.clone()));
+    }
+
+    #[test]
+    fn event_async_sub_assign_does_not_panic_if_type_did_not_subscribe() {
+        let mut event = EventAsync::default();
+
+        let test_sub = TestSubscriber::default().into_arc();
+
+        event -= test_sub;
+
+        assert!(event.get_subscribers().is_empty());
+    }
+
+}
@@ -0,0 +1,344 @@
+use std::{future::Future, pin::{pin, Pin}, sync::Arc, task::{Context, Poll, Wake, Waker}, thread::{self, Thread}, panic::{self, AssertUnwindSafe}};
+
+use crate::{
+    subscriber::AsyncSubscriber,
+    subscription::{Registered, SubscriptionGuard, SubscriptionId},
+    error::RustventError,
+    events::{EventConfig, Notify, Clear, Await},
+    report::NotificationReport,
+    stats::{EventStats, StatsCounter}
+};
+
+/// A boxed future returned by an [AsyncSubscriber].
+pub type BoxFuture<'a, R> = Pin<Box<dyn Future<Output = R> + Send + 'a>>;
+
+/// A closure subscribed to an [EventFuture] through [subscribe_as_fn_with()](EventFuture::subscribe_as_fn_with()).
+pub type FnSubscriberFuture<T> = Box<dyn Fn(&T) -> BoxFuture<'static, ()> + Send + Sync>;
+
+/// The **async/await** counterpart of [Event](crate::events::Event). Subscribers return futures, which
+/// `notify` awaits concurrently or one after the other depending on the [Await] of this event, so no
+/// thread is blocked per subscriber.
+///
+/// The event does not depend on any runtime: `notify` may be awaited on any executor, or driven
+/// to completion with [block_on()].
+/// # Examples
+/// ```
+/// let mut order_placed: EventFuture<u32> = EventFuture::default();
+/// order_placed.subscribe_as_fn_with(|order_id| {
+///     let order_id = *order_id;
+///     async move { println!("Order {order_id} placed") }
+/// });
+///
+/// block_on(order_placed.notify_with(&42));
+/// ```
+pub struct EventFuture<T = ()> {
+    subscribers: Vec<Registered<Arc<dyn AsyncSubscriber<T> + Send + Sync>>>,
+    fn_subscribers: Vec<Registered<FnSubscriberFuture<T>>>,
+    config: EventConfig,
+    stats: StatsCounter
+}
+
+/// Identifies a subscription to an [EventFuture] that can be removed with [unsubscribe()](EventFuture::unsubscribe()).
+/// Any [SubscriptionId] returned by the event, or the [AsyncSubscriber] that was subscribed, converts into one.
+pub enum SubscriptionFuture<T = ()> {
+    Id(SubscriptionId),
+    Subscriber(Arc<dyn AsyncSubscriber<T> + Send + Sync>),
+}
+
+impl<T> EventFuture<T> {
+    pub fn new(config: EventConfig) -> EventFuture<T> {
+        EventFuture {
+            subscribers: Vec::new(),
+            fn_subscribers: Vec::new(),
+            config,
+            stats: StatsCounter::default()
+        }
+    }
+
+    pub fn get_subscribers(&self) -> Vec<Arc<dyn AsyncSubscriber<T> + Send + Sync>> {
+        self.subscribers
+        .iter()
+        .filter(|sub| sub.is_active())
+        .map(|sub| sub.subscriber.clone())
+        .collect()
+    }
+
+    pub fn get_fn_subscribers(&self) -> Vec<&FnSubscriberFuture<T>> {
+        self.fn_subscribers
+        .iter()
+        .filter(|func| func.is_active())
+        .map(|func| &func.subscriber)
+        .collect()
+    }
+
+    pub fn subscribe(&mut self, subscriber: Arc<dyn AsyncSubscriber<T> + Send + Sync>) -> SubscriptionId {
+        let registered = Registered::new(subscriber);
+        let id = registered.id;
+        self.subscribers.push(registered);
+        id
+    }
+
+    /// Subscribe a closure returning a future. The future may not borrow the payload, so copy
+    /// what it needs out of the payload before the `async` block.
+    pub fn subscribe_as_fn_with<F, Fut>(&mut self, subscriber: F) -> SubscriptionId
+    where F: Fn(&T) -> Fut + Send + Sync + 'static, Fut: Future<Output = ()> + Send + 'static {
+        let func: FnSubscriberFuture<T> = Box::new(move |args| Box::pin(subscriber(args)));
+        let registered = Registered::new(func);
+        let id = registered.id;
+        self.fn_subscribers.push(registered);
+        id
+    }
+
+    /// Subscribe an [AsyncSubscriber] for as long as the returned [SubscriptionGuard] is alive.
+    pub fn subscribe_scoped(&mut self, subscriber: Arc<dyn AsyncSubscriber<T> + Send + Sync>) -> SubscriptionGuard {
+        let (registered, guard) = Registered::scoped(subscriber);
+        self.subscribers.push(registered);
+        guard
+    }
+
+    /// Unsubscribe from this event, either by the [SubscriptionId] returned when subscribing or by the
+    /// [AsyncSubscriber] itself.
+    ///
+    /// # Panics
+    /// Panics if the subscriber is not subscribed to this event. See [try_unsubscribe()](EventFuture::try_unsubscribe())
+    /// and [unsubscribe_if_present()](EventFuture::unsubscribe_if_present()) for non-panicking alternatives.
+    pub fn unsubscribe(&mut self, subscription: impl Into<SubscriptionFuture<T>>) {
+        if let Err(err) = self.try_unsubscribe(subscription) {
+            panic!("{err}");
+        }
+    }
+
+    /// Like [unsubscribe()](EventFuture::unsubscribe()), but returns an error instead of panicking
+    /// when the subscriber is not subscribed to this event.
+    pub fn try_unsubscribe(&mut self, subscription: impl Into<SubscriptionFuture<T>>) -> Result<(), RustventError> {
+        self.remove_cancelled();
+
+        match subscription.into() {
+            SubscriptionFuture::Id(id) => {
+                if let Some(index) = self.subscribers.iter().position(|sub| sub.id == id) {
+                    self.subscribers.remove(index);
+                } else if let Some(index) = self.fn_subscribers.iter().position(|func| func.id == id) {
+                    self.fn_subscribers.remove(index);
+                } else {
+                    return Err(RustventError::SubscriptionNotFound(id));
+                }
+
+                Ok(())
+            },
+            SubscriptionFuture::Subscriber(subscriber) => {
+                let index = self.subscribers
+                .iter()
+                .position(|sub| Arc::ptr_eq(&subscriber, &sub.subscriber))
+                .ok_or(RustventError::SubscriberNotFound)?;
+
+                self.subscribers.remove(index);
+                Ok(())
+            }
+        }
+    }
+
+    /// Removes the subscriber if it is still subscribed to this event, and does nothing otherwise.
+    /// Returns whether a subscriber was removed.
+    pub fn unsubscribe_if_present(&mut self, subscription: impl Into<SubscriptionFuture<T>>) -> bool {
+        self.try_unsubscribe(subscription).is_ok()
+    }
+
+    /// Drops subscribers whose [SubscriptionGuard] has been dropped.
+    fn remove_cancelled(&mut self) {
+        self.subscribers.retain(|sub| sub.is_active());
+        self.fn_subscribers.retain(|func| func.is_active());
+    }
+
+    /// A snapshot of the statistics of this event.
+    pub fn stats(&self) -> EventStats {
+        self.stats.snapshot()
+    }
+
+    /// Sets every statistic of this event back to zero.
+    pub fn reset_stats(&self) {
+        self.stats.reset();
+    }
+
+    /// Unsubscribes every subscriber in `selection`, as the event does after each notification
+    /// according to its [EventConfig]. An [EventFuture] has no mutable subscribers.
+    pub fn clear(&mut self, selection: Clear) {
+        if selection.contains(Clear::SUBSCRIBERS) {
+            self.subscribers.clear();
+        }
+        if selection.contains(Clear::FN_SUBSCRIBERS) {
+            self.fn_subscribers.clear();
+        }
+    }
+}
+
+impl<T: Sync> EventFuture<T> {
+    /// Notifies subscribers with a reference to `args` and awaits their futures as configured by the
+    /// [Await] of this event. Resolves with a [NotificationReport] listing the subscribers that panicked;
+    /// a panicking future does not prevent the others from completing.
+    pub async fn notify_with(&mut self, args: &T) -> NotificationReport {
+        self.remove_cancelled();
+
+        let selection = self.config.subscribers_to_notify;
+
+        let mut futures: Vec<(SubscriptionId, BoxFuture<'_, ()>)> = Vec::new();
+
+        if selection.contains(Notify::SUBSCRIBERS) {
+            let queued = futures.len();
+            for sub in self.subscribers.iter().filter(|sub| sub.is_active()) {
+                futures.push((sub.id, sub.subscriber.update_with(args)));
+            }
+            if futures.len() > queued { self.stats.record_subscribers_notified(); }
+        }
+
+        if selection.contains(Notify::FN_SUBSCRIBERS) {
+            let queued = futures.len();
+            for func in self.fn_subscribers.iter().filter(|func| func.is_active()) {
+                futures.push((func.id, (func.subscriber)(args)));
+            }
+            if futures.len() > queued { self.stats.record_fn_subscribers_notified(); }
+        }
+
+        self.stats.record_invocations(futures.len());
+
+        let results = match self.config.await_subscribers {
+            Await::Concurrently => JoinAll::new(futures).await,
+            Await::Sequentially => {
+                let mut results = Vec::new();
+                for (id, future) in futures {
+                    results.push((id, CatchUnwind(future).await));
+                }
+                results
+            }
+        };
+
+        let mut report = NotificationReport::default();
+        for (id, result) in results {
+            if let Err(payload) = result {
+                report.push_panic(id, payload);
+            }
+        }
+
+        self.clear(self.config.clear_subscribers_after_notification);
+        self.stats.record_notification(&report);
+        report
+    }
+}
+
+impl EventFuture<()> {
+    pub fn subscribe_as_fn<F, Fut>(&mut self, subscriber: F) -> SubscriptionId
+    where F: Fn() -> Fut + Send + Sync + 'static, Fut: Future<Output = ()> + Send + 'static {
+        self.subscribe_as_fn_with(move |_| subscriber())
+    }
+
+    pub async fn notify(&mut self) -> NotificationReport {
+        self.notify_with(&()).await
+    }
+}
+
+/// Runs `future` to completion on the current thread, parking it while the future is pending.
+/// Allows notifying an [EventFuture] without an async runtime.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        match future.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Resolves with the panic payload of the wrapped future instead of propagating it.
+struct CatchUnwind<'a>(BoxFuture<'a, ()>);
+
+impl Future for CatchUnwind<'_> {
+    type Output = thread::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match panic::catch_unwind(AssertUnwindSafe(|| self.0.as_mut().poll(cx))) {
+            Ok(Poll::Ready(())) => Poll::Ready(Ok(())),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
+}
+
+/// Polls every future together, resolving with their results in order once all of them completed.
+struct JoinAll<'a> {
+    pending: Vec<(SubscriptionId, Option<CatchUnwind<'a>>)>,
+    results: Vec<Option<thread::Result<()>>>,
+}
+
+impl<'a> JoinAll<'a> {
+    fn new(futures: Vec<(SubscriptionId, BoxFuture<'a, ()>)>) -> JoinAll<'a> {
+        let results = futures.iter().map(|_| None).collect();
+        let pending = futures
+        .into_iter()
+        .map(|(id, future)| (id, Some(CatchUnwind(future))))
+        .collect();
+
+        JoinAll { pending, results }
+    }
+}
+
+impl Future for JoinAll<'_> {
+    type Output = Vec<(SubscriptionId, thread::Result<()>)>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = &mut *self;
+        let mut done = true;
+
+        for ((_, slot), result) in this.pending.iter_mut().zip(this.results.iter_mut()) {
+            if let Some(future) = slot {
+                match Pin::new(future).poll(cx) {
+                    Poll::Ready(output) => {
+                        *result = Some(output);
+                        *slot = None;
+                    },
+                    Poll::Pending => done = false,
+                }
+            }
+        }
+
+        if !done {
+            return Poll::Pending;
+        }
+
+        let results = this.results.drain(..).map(|result| result.unwrap());
+        Poll::Ready(this.pending.iter().map(|(id, _)| *id).zip(results).collect())
+    }
+}
+
+impl<T> Default for EventFuture<T> {
+    fn default() -> Self {
+        Self::new(EventConfig::default())
+    }
+}
+
+impl<T> From<SubscriptionId> for SubscriptionFuture<T> {
+    fn from(id: SubscriptionId) -> Self {
+        SubscriptionFuture::Id(id)
+    }
+}
+
+impl<T> From<Arc<dyn AsyncSubscriber<T> + Send + Sync>> for SubscriptionFuture<T> {
+    fn from(subscriber: Arc<dyn AsyncSubscriber<T> + Send + Sync>) -> Self {
+        SubscriptionFuture::Subscriber(subscriber)
+    }
+}
+
+impl<T, S> From<Arc<S>> for SubscriptionFuture<T> where S: AsyncSubscriber<T> + Send + Sync + 'static {
+    fn from(subscriber: Arc<S>) -> Self {
+        SubscriptionFuture::Subscriber(subscriber)
+    }
+}
@@ -1,70 +1,270 @@
 //! A crate that implements the observer pattern.
+//!
+//! With the `tracing` feature, [Event](events::Event) and [EventAsync](event_async::EventAsync) emit `tracing`
+//! spans and events when subscribers subscribe, unsubscribe, are notified, fail or panic, and when an event
+//! is cleared. The `log` feature also forwards them to the `log` crate.
 
+#[macro_use]
+mod trace;
 pub mod subscriber;
+pub mod subscription;
+pub mod error;
+pub mod report;
+pub mod args;
 pub mod event_async;
+pub mod event_future;
+pub mod pool;
+pub mod bus;
+pub mod topic;
+pub mod request;
+pub mod reentrant;
+pub mod stats;
+pub mod notifier;
 pub mod macros;
-use std::{rc::Rc, ptr};
-use subscriber::{Subscriber, SubscriberMut};
-use std::ops::{AddAssign, SubAssign};
+use std::{collections::HashSet, rc::{Rc, Weak}};
+use subscriber::{Subscriber, SubscriberMut, TrySubscriber};
+use subscription::{remove_exhausted, DispatchOrder, Registered, SubscriptionGuard, SubscriptionId};
+use error::{RustventError, SubscriberError};
+use report::NotificationReport;
+use args::Cancellable;
+use stats::{EventStats, LatencyHistogram, StatsCounter};
+use std::ops::{BitOr, Sub, SubAssign};
 use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use pool::ThreadPool;
 
 #[macro_use]
 pub mod events {
     use super::*;
 
+    /// A closure subscribed to an [Event] through [subscribe_as_fn_with()](Event::subscribe_as_fn_with()).
+    pub type FnSubscriber<T> = Box<dyn Fn(&T)>;
+
+    /// Decides whether a subscriber of an [Event] is notified, see [subscribe_filtered()](Event::subscribe_filtered()).
+    pub type Predicate<T> = Box<dyn Fn(&T) -> bool>;
+
     /// Provides a **synchronous** mechanism for [Subscribers](Subscriber) to
     /// register themselves with a source, in this case an [Event]. Events can then
     /// notify subscribers of state changes.
-    #[derive(Default)]
-    pub struct Event {
-        pub times_subscribers_notified: u32,
-        pub times_subscribers_mut_notified: u32,
-        pub times_func_subscribers_notified: u32,
-        subscribers: Vec<Rc<dyn Subscriber>>,
-        subscribers_mut: Vec<Rc<RefCell<dyn SubscriberMut>>>,
-        fn_subscribers: Vec<Box<dyn Fn() -> ()>>,
+    ///
+    /// The type parameter `T` is the payload handed to every subscriber when the event is
+    /// notified with [notify_with()](Event::notify_with()). It defaults to `()`, for events
+    /// that only need to say that something happened.
+    pub struct Event<T = ()> {
+        subscribers: Vec<Registered<Rc<dyn Subscriber<T>>, Predicate<T>>>,
+        weak_subscribers: Vec<Registered<Weak<dyn Subscriber<T>>>>,
+        subscribers_mut: Vec<Registered<Rc<RefCell<dyn SubscriberMut<T>>>>>,
+        fn_subscribers: Vec<Registered<FnSubscriber<T>>>,
+        try_subscribers: Vec<Registered<Rc<dyn TrySubscriber<T>>>>,
         config: EventConfig,
+        stats: StatsCounter,
+    }
+
+    /// Identifies a subscription to an [Event] that can be removed with [unsubscribe()](Event::unsubscribe()).
+    /// Any [SubscriptionId] returned by the event, or the [Subscriber] that was subscribed, converts into one.
+    pub enum Subscription<T = ()> {
+        Id(SubscriptionId),
+        Subscriber(Rc<dyn Subscriber<T>>),
     }
 
     /// Provides values to configure individual [Events](Event). 
     pub struct EventConfig {
+        /// Identifies the event in the spans and events emitted with the `tracing` feature. Defaults to
+        /// the type name of the payload of the event.
+        pub name: Option<&'static str>,
         pub subscribers_to_notify: Notify,
-        pub clear_subscribers_after_notification: Clear
+        pub clear_subscribers_after_notification: Clear,
+        pub on_subscriber_error: ErrorPolicy,
+        pub on_poisoned_subscriber: PoisonPolicy,
+        /// When `true`, an [Event] catches a panicking subscriber, keeps notifying the others and lists it in
+        /// [NotificationReport::panics()]. When `false`, the panic unwinds out of `notify`.
+        /// An [EventAsync](crate::event_async::EventAsync) always catches panics.
+        pub catch_panics: bool,
+        /// How an [EventAsync](crate::event_async::EventAsync) runs its subscribers. Has no effect on an [Event].
+        pub dispatch: Dispatch,
+        /// How an [EventFuture](crate::event_future::EventFuture) awaits its subscribers. Has no effect on other events.
+        pub await_subscribers: Await,
+        /// How a [ReentrantEvent](crate::reentrant::ReentrantEvent) handles being notified by one of its own
+        /// subscribers. Has no effect on other events.
+        pub reentrancy: Reentrancy,
+        /// When set, an [Event] or [EventAsync](crate::event_async::EventAsync) hands every subscriber that
+        /// took at least this long to be notified to its [SlowHandlerPolicy].
+        pub slow_handler_threshold: Option<Duration>,
+        pub on_slow_handler: SlowHandlerPolicy,
+        /// When `true`, an [Event] or [EventAsync](crate::event_async::EventAsync) keeps a latency histogram
+        /// of each subscriber, see [Event::latencies()]. Recording takes a lock per notified subscriber.
+        pub record_latency: bool,
     }
 
-    /// When used in conjunction with [EventConfig], this allows for configuring
-    /// which subscribers to an event are notified.
-    pub enum Notify {
-        /// Implementors of the [Subscriber] Trait and closures. 
-        All,
+    /// Defines a set of kinds of subscribers, combined with `|` and removed with `-`.
+    macro_rules! selection {
+        ($(#[$attr:meta])* $name:ident) => {
+            $(#[$attr])*
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            pub struct $name(u8);
+
+            impl $name {
+                /// Implementors of the [Subscriber] and [TrySubscriber] Traits, weak subscribers included.
+                pub const SUBSCRIBERS: $name = $name(1);
+                /// Implementors of the [SubscriberMut] Trait.
+                pub const SUBSCRIBERS_MUT: $name = $name(1 << 1);
+                /// Closures.
+                pub const FN_SUBSCRIBERS: $name = $name(1 << 2);
+                /// Every kind of subscriber.
+                pub const ALL: $name = $name(0b111);
+                /// No subscriber.
+                pub const NONE: $name = $name(0);
+
+                /// Whether every kind of subscriber in `other` is part of this selection.
+                pub fn contains(self, other: $name) -> bool {
+                    self.0 & other.0 == other.0
+                }
+
+                pub fn is_empty(self) -> bool {
+                    self.0 == 0
+                }
+            }
+
+            impl BitOr for $name {
+                type Output = $name;
+
+                fn bitor(self, rhs: $name) -> $name {
+                    $name(self.0 | rhs.0)
+                }
+            }
+
+            impl Sub for $name {
+                type Output = $name;
+
+                fn sub(self, rhs: $name) -> $name {
+                    $name(self.0 & !rhs.0)
+                }
+            }
+        };
+    }
+
+    selection!(
+        /// When used in conjunction with [EventConfig], this allows for configuring
+        /// which subscribers to an event are notified. Kinds of subscribers are combined with `|`,
+        /// e.g. `Notify::SUBSCRIBERS | Notify::SUBSCRIBERS_MUT` leaves closures out.
+        Notify
+    );
+
+    selection!(
+        /// When used in conjunction with [EventConfig], this allows for configuring which subscribers
+        /// are unsubscribed after an event is notified. Kinds of subscribers are combined with `|`,
+        /// e.g. `Clear::FN_SUBSCRIBERS | Clear::SUBSCRIBERS_MUT`.
+        Clear
+    );
+
+    /// Shorthands for the most common selections, kept from when [Notify] was an enum.
+    #[allow(non_upper_case_globals)]
+    impl Notify {
+        /// Implementors of the [Subscriber] and [SubscriberMut] Traits and closures.
+        pub const All: Notify = Notify::ALL;
         /// Only implementors of the [Subscriber] Trait.
-        OnlySubscribers,
+        pub const OnlySubscribers: Notify = Notify::SUBSCRIBERS;
         /// Only implementors of the [SubscriberMut] Trait.
-        OnlySubscribersMut,
+        pub const OnlySubscribersMut: Notify = Notify::SUBSCRIBERS_MUT;
         /// Only closures.
-        OnlyFnSubscribers
+        pub const OnlyFnSubscribers: Notify = Notify::FN_SUBSCRIBERS;
+    }
+
+    /// Shorthands for the most common selections, kept from when [Clear] was an enum.
+    #[allow(non_upper_case_globals)]
+    impl Clear {
+        /// Every kind of subscriber, mutable subscribers included.
+        pub const All: Clear = Clear::ALL;
+        pub const OnlySubscribers: Clear = Clear::SUBSCRIBERS;
+        pub const OnlySubscribersMut: Clear = Clear::SUBSCRIBERS_MUT;
+        pub const OnlyFuncSubscribers: Clear = Clear::FN_SUBSCRIBERS;
+        pub const None: Clear = Clear::NONE;
+    }
+
+    /// When used in conjunction with [EventConfig], this allows for configuring how errors
+    /// returned by a [TrySubscriber] are handled.
+    pub enum ErrorPolicy {
+        /// Stop notifying subscribers as soon as one of them fails. The error is returned in the [NotificationReport].
+        StopAtFirst,
+        /// Keep notifying subscribers and return every error in the [NotificationReport].
+        CollectAll,
+        /// Leave errors out of the [NotificationReport], logging them through `tracing` with the `tracing` feature.
+        /// They are still counted in [EventStats::failures].
+        LogAndIgnore,
+    }
+
+    /// When used in conjunction with [EventConfig], this allows for configuring what an
+    /// [EventAsync](crate::event_async::EventAsync) does with a mutable subscriber whose `Mutex` was
+    /// poisoned by a previous panic. Has no effect on an [Event].
+    pub enum PoisonPolicy {
+        /// Clear the poison and keep notifying the subscriber.
+        Recover,
+        /// Unsubscribe the subscriber and list it in [NotificationReport::evicted()].
+        Evict,
+        /// Panic on the thread notifying the event.
+        Propagate,
+    }
+
+    /// When used in conjunction with [EventConfig], this allows for configuring which threads
+    /// an [EventAsync](crate::event_async::EventAsync) notifies its subscribers on.
+    pub enum Dispatch {
+        /// Spawn a thread per subscriber for every notification.
+        ThreadPerSubscriber,
+        /// Notify subscribers in parallel on the workers of a [ThreadPool], which may be shared between events.
+        Pool(Arc<ThreadPool>),
+        /// Notify subscribers one after the other on the thread calling `notify`.
+        Sequential,
+    }
+
+    /// When used in conjunction with [EventConfig], this allows for configuring how an
+    /// [EventFuture](crate::event_future::EventFuture) awaits the futures of its subscribers.
+    pub enum Await {
+        /// Poll every future together until all of them completed.
+        Concurrently,
+        /// Await each future before starting the next one, in the order subscribers were added.
+        Sequentially,
+    }
+
+    /// A closure called with the id of a slow subscriber and the time it took, see [SlowHandlerPolicy::Callback].
+    pub type SlowHandlerCallback = Arc<dyn Fn(SubscriptionId, Duration) + Send + Sync>;
+
+    /// When used in conjunction with [EventConfig], this allows for configuring what is done with the
+    /// subscribers that were slower than the [slow_handler_threshold](EventConfig::slow_handler_threshold).
+    pub enum SlowHandlerPolicy {
+        /// List them in [NotificationReport::slow_handlers()].
+        Report,
+        /// Log them through `tracing`. Without the `tracing` feature, they are dropped.
+        Log,
+        /// Call the closure right after each of them was notified. With an
+        /// [EventAsync](crate::event_async::EventAsync), it is called once every subscriber was notified.
+        Callback(SlowHandlerCallback),
     }
 
-    pub enum Clear {
-        All,
-        OnlySubscribers,
-        OnlySubscribersMut,
-        OnlyFuncSubscribers,
-        None
+    /// When used in conjunction with [EventConfig], this allows for configuring what a
+    /// [ReentrantEvent](crate::reentrant::ReentrantEvent) does when a subscriber notifies it again
+    /// while it is notifying its subscribers.
+    pub enum Reentrancy {
+        /// Notify the subscribers with the new payload once the current notification completed.
+        Queue,
+        /// Notify the subscribers right away, from inside the subscriber, unless `max_depth` notifications
+        /// are already in progress, in which case [RustventError::MaxDepthExceeded] is returned.
+        Recurse { max_depth: u32 },
     }
 
-    impl Event {
+    impl<T> Event<T> {
         /// Creates a new [Event] with the provided [EventConfig]
         /// being used to determine the default behavior of this particular Event. 
-        pub fn new(config: EventConfig) -> Event {
+        pub fn new(config: EventConfig) -> Event<T> {
             Event { 
                 subscribers: Default::default(), 
+                weak_subscribers: Default::default(),
                 subscribers_mut: Default::default(),
                 fn_subscribers: Default::default(), 
-                times_subscribers_notified: Default::default(), 
-                times_subscribers_mut_notified: Default::default(), 
-                times_func_subscribers_notified: Default::default(), 
+                try_subscribers: Default::default(),
                 config, 
+                stats: Default::default(),
             }
         }
 
@@ -82,58 +282,613 @@ pub mod events {
         /// 
         /// let mut some_event = Event::default();
         /// let rc_new_sub = Rc::new(NewSubscriber {});
-        /// some_event.subscribe(rc_new_sub.clone());
+        /// let subscription = some_event.subscribe(rc_new_sub.clone());
         /// // some_event += rc_new_sub.clone() - Equivalent to the line above. 
+        ///
+        /// some_event.unsubscribe(subscription);
         /// ```
-        pub fn subscribe(&mut self, subscriber: Rc<dyn Subscriber>) {
-            self.subscribers.push(subscriber);
+        pub fn subscribe(&mut self, subscriber: Rc<dyn Subscriber<T>>) -> SubscriptionId {
+            self.subscribe_with_priority(subscriber, 0)
         }
 
-        pub fn subscribe_mut(&mut self, subscriber: Rc<RefCell<dyn SubscriberMut>>) {
-            self.subscribers_mut.push(subscriber);
+        /// Like [subscribe()](Event::subscribe()), but controls when the subscriber is notified. Subscribers of
+        /// every kind, closures included, are notified by descending priority, then in the order they subscribed.
+        /// Subscribers that do not specify a priority have a priority of `0`.
+        /// # Examples
+        /// ```
+        /// let mut order_placed: Event<u32> = Event::default();
+        /// order_placed.subscribe_as_fn_with_priority(|_| println!("Persisting..."), 0);
+        /// order_placed.subscribe_with_priority(Rc::new(Validator {}), 10); // Notified first.
+        /// ```
+        pub fn subscribe_with_priority(&mut self, subscriber: Rc<dyn Subscriber<T>>, priority: i32) -> SubscriptionId {
+            let registered = Registered::new(subscriber).with_priority(priority);
+            let id = registered.id;
+            self.subscribers.push(registered);
+            self.subscribed(id, "subscriber")
         }
 
-        /// Subscribe to an event with a closure.
-        /// Need to subscribe to an event with a Struct? See [subscribe()](Event::subscribe()).
+        pub fn subscribe_mut(&mut self, subscriber: Rc<RefCell<dyn SubscriberMut<T>>>) -> SubscriptionId {
+            self.subscribe_mut_with_priority(subscriber, 0)
+        }
+
+        /// Like [subscribe_mut()](Event::subscribe_mut()), see [subscribe_with_priority()](Event::subscribe_with_priority()).
+        pub fn subscribe_mut_with_priority(&mut self, subscriber: Rc<RefCell<dyn SubscriberMut<T>>>, priority: i32) -> SubscriptionId {
+            let registered = Registered::new(subscriber).with_priority(priority);
+            let id = registered.id;
+            self.subscribers_mut.push(registered);
+            self.subscribed(id, "subscriber_mut")
+        }
+
+        /// Subscribe to an event with a closure that receives the payload of the event.
+        /// For an `Event<()>` you may prefer [subscribe_as_fn()](Event::subscribe_as_fn()).
         /// # Examples
         /// ```
+        /// let mut order_changed: Event<u32> = Event::default();
+        /// order_changed.subscribe_as_fn_with(|order_id| println!("Order {order_id} changed..."));
+        /// ```
+        pub fn subscribe_as_fn_with<F>(&mut self, func: F) -> SubscriptionId where F: Fn(&T) + 'static {
+            self.subscribe_as_fn_with_priority(func, 0)
+        }
+
+        /// Like [subscribe_as_fn_with()](Event::subscribe_as_fn_with()), see [subscribe_with_priority()](Event::subscribe_with_priority()).
+        pub fn subscribe_as_fn_with_priority<F>(&mut self, func: F, priority: i32) -> SubscriptionId where F: Fn(&T) + 'static {
+            let box_func: FnSubscriber<T> = Box::new(func);
+            let registered = Registered::new(box_func).with_priority(priority);
+            let id = registered.id;
+            self.fn_subscribers.push(registered);
+            self.subscribed(id, "fn")
+        }
+
+        /// Allows any implementors of the [TrySubscriber] Trait to subscribe to this event. Errors returned by
+        /// the subscriber are handled according to the [ErrorPolicy] of this event. [TrySubscribers](TrySubscriber)
+        /// are configured like [Subscribers](Subscriber), e.g. by [Notify::SUBSCRIBERS].
+        /// # Examples
+        /// ```
+        /// struct Validator {}
+        /// impl TrySubscriber<u32> for Validator {
+        ///     fn try_update_with(&self, quantity: &u32) -> Result<(), SubscriberError> {
+        ///         if *quantity == 0 {
+        ///             return Err("quantity must be positive".into());
+        ///         }
+        ///         Ok(())
+        ///     }
+        /// }
+        ///
+        /// let mut order_placed: Event<u32> = Event::default();
+        /// order_placed.subscribe_try(Rc::new(Validator {}));
+        ///
+        /// let report = order_placed.notify_with(&0);
+        /// assert_eq!(1, report.failures().len());
+        /// ```
+        pub fn subscribe_try(&mut self, subscriber: Rc<dyn TrySubscriber<T>>) -> SubscriptionId {
+            self.subscribe_try_with_priority(subscriber, 0)
+        }
+
+        /// Like [subscribe_try()](Event::subscribe_try()), see [subscribe_with_priority()](Event::subscribe_with_priority()).
+        pub fn subscribe_try_with_priority(&mut self, subscriber: Rc<dyn TrySubscriber<T>>, priority: i32) -> SubscriptionId {
+            let registered = Registered::new(subscriber).with_priority(priority);
+            let id = registered.id;
+            self.try_subscribers.push(registered);
+            self.subscribed(id, "try")
+        }
+
+        /// Subscribe a [Subscriber] for as long as the returned [SubscriptionGuard] is alive.
+        /// Dropping the guard unsubscribes it, so the event no longer keeps the subscriber alive.
+        /// # Examples
+        /// ```
+        /// struct Panel {}
+        /// impl Subscriber for Panel {}
+        ///
         /// let mut some_event = Event::default();
-        /// some_event.subscribe_as_fn(|| println!("Closure notified..."));
+        /// let guard = some_event.subscribe_scoped(Rc::new(Panel {}));
+        ///
+        /// drop(guard); // Panel is no longer subscribed.
         /// ```
-        pub fn subscribe_as_fn<F>(&mut self, func: F) where F: Fn() -> () + 'static {
-            let box_func = Box::new(func);
-            self.fn_subscribers.push(box_func);
+        pub fn subscribe_scoped(&mut self, subscriber: Rc<dyn Subscriber<T>>) -> SubscriptionGuard {
+            let (registered, guard) = Registered::scoped(subscriber);
+            self.subscribers.push(registered);
+            self.subscribed(guard.id(), "subscriber");
+            guard
+        }
+
+        /// Subscribe a [SubscriberMut] for as long as the returned [SubscriptionGuard] is alive.
+        pub fn subscribe_mut_scoped(&mut self, subscriber: Rc<RefCell<dyn SubscriberMut<T>>>) -> SubscriptionGuard {
+            let (registered, guard) = Registered::scoped(subscriber);
+            self.subscribers_mut.push(registered);
+            self.subscribed(guard.id(), "subscriber_mut");
+            guard
         }
 
-        /// Unsubscribe a [Subscriber] from this event.
-        /// Alternatively you may use the `SubAssign` operator (`-=`) to unsubscribe to an event.
-        pub fn unsubscribe(&mut self, subscriber: Rc<dyn Subscriber>) {
-            let index = self.contains(subscriber)
-            .expect("The provided 'subscriber' argument could not be found in the list of subscribers.");
-            
-            self.subscribers.swap_remove(index);
+        /// Subscribe a closure for as long as the returned [SubscriptionGuard] is alive.
+        pub fn subscribe_as_fn_scoped<F>(&mut self, func: F) -> SubscriptionGuard where F: Fn(&T) + 'static {
+            let box_func: FnSubscriber<T> = Box::new(func);
+            let (registered, guard) = Registered::scoped(box_func);
+            self.fn_subscribers.push(registered);
+            self.subscribed(guard.id(), "fn");
+            guard
         }
 
-        pub fn unsubscribe_mut(&mut self, subscriber: Rc<RefCell<dyn SubscriberMut>>) {
-            let index = self.contains_mut(subscriber)
-            .expect("The provided 'subscriber' argument could not be found in the list of subscribers.");
-            
-            self.subscribers_mut.swap_remove(index);
+        /// Subscribe a [Subscriber] that is only notified when `predicate` returns `true` for the payload of
+        /// the notification. A subscriber filtered out of a notification is not invoked and does not count
+        /// towards [EventStats::subscribers_notified].
+        /// # Examples
+        /// ```
+        /// let mut order_placed: Event<u32> = Event::default();
+        /// order_placed.subscribe_filtered(Rc::new(BulkOrderDesk {}), |quantity| *quantity >= 100);
+        ///
+        /// order_placed.notify_with(&5); // BulkOrderDesk is not notified.
+        /// ```
+        pub fn subscribe_filtered<F>(&mut self, subscriber: Rc<dyn Subscriber<T>>, predicate: F) -> SubscriptionId where F: Fn(&T) -> bool + 'static {
+            let predicate: Predicate<T> = Box::new(predicate);
+            let registered = Registered::new(subscriber).with_filter(predicate);
+            let id = registered.id;
+            self.subscribers.push(registered);
+            self.subscribed(id, "subscriber")
+        }
+
+        /// Subscribe a [Subscriber] without keeping it alive. Once every `Rc` to the subscriber is dropped it is
+        /// no longer notified, and the event prunes it on its next notification, counting it in
+        /// [EventStats::dead_subscribers_pruned]. This lets a subscriber own the event
+        /// it subscribes to without creating a reference cycle.
+        /// # Examples
+        /// ```
+        /// let mut some_event = Event::default();
+        /// let panel = Rc::new(Panel {});
+        /// some_event.subscribe_weak(Rc::downgrade(&panel) as Weak<dyn Subscriber>);
+        ///
+        /// drop(panel); // Panel is no longer notified.
+        /// ```
+        pub fn subscribe_weak(&mut self, subscriber: Weak<dyn Subscriber<T>>) -> SubscriptionId {
+            let registered = Registered::new(subscriber);
+            let id = registered.id;
+            self.weak_subscribers.push(registered);
+            self.subscribed(id, "weak")
+        }
+
+        /// Subscribe a [Subscriber] that is unsubscribed after its first notification, while the other
+        /// subscribers of this event stay subscribed.
+        /// # Examples
+        /// ```
+        /// let mut app_started = Event::default();
+        /// app_started.subscribe_once(Rc::new(Splash {}));
+        ///
+        /// app_started.notify(); // Splash is notified, then unsubscribed.
+        /// ```
+        pub fn subscribe_once(&mut self, subscriber: Rc<dyn Subscriber<T>>) -> SubscriptionId {
+            self.subscribe_n(subscriber, 1)
+        }
+
+        /// Subscribe a [Subscriber] that is unsubscribed after `times` notifications.
+        ///
+        /// # Panics
+        /// Panics if `times` is zero.
+        pub fn subscribe_n(&mut self, subscriber: Rc<dyn Subscriber<T>>, times: u32) -> SubscriptionId {
+            let registered = Registered::new(subscriber).with_limit(times);
+            let id = registered.id;
+            self.subscribers.push(registered);
+            self.subscribed(id, "subscriber")
+        }
+
+        /// Subscribe a closure that is unsubscribed after its first notification.
+        pub fn subscribe_as_fn_once<F>(&mut self, func: F) -> SubscriptionId where F: Fn(&T) + 'static {
+            self.subscribe_as_fn_n(func, 1)
+        }
+
+        /// Subscribe a closure that is unsubscribed after `times` notifications.
+        ///
+        /// # Panics
+        /// Panics if `times` is zero.
+        pub fn subscribe_as_fn_n<F>(&mut self, func: F, times: u32) -> SubscriptionId where F: Fn(&T) + 'static {
+            let box_func: FnSubscriber<T> = Box::new(func);
+            let registered = Registered::new(box_func).with_limit(times);
+            let id = registered.id;
+            self.fn_subscribers.push(registered);
+            self.subscribed(id, "fn")
+        }
+
+        /// Unsubscribe from this event, either by the [SubscriptionId] returned when subscribing or by the
+        /// [Subscriber] itself. Unsubscribing by id works for every kind of subscriber, including closures.
+        /// Alternatively you may use the `SubAssign` operator (`-=`), which unlike this method does nothing
+        /// if the subscriber is no longer subscribed.
+        ///
+        /// # Panics
+        /// Panics if the subscriber is not subscribed to this event. See [try_unsubscribe()](Event::try_unsubscribe())
+        /// and [unsubscribe_if_present()](Event::unsubscribe_if_present()) for non-panicking alternatives.
+        /// # Examples
+        /// ```
+        /// let mut some_event = Event::default();
+        /// let subscription = some_event.subscribe_as_fn(|| println!("Closure notified..."));
+        ///
+        /// some_event.unsubscribe(subscription);
+        /// ```
+        pub fn unsubscribe(&mut self, subscription: impl Into<Subscription<T>>) {
+            if let Err(err) = self.try_unsubscribe(subscription) {
+                panic!("{err}");
+            }
+        }
+
+        /// # Panics
+        /// Panics if the subscriber is not subscribed to this event. See [try_unsubscribe_mut()](Event::try_unsubscribe_mut())
+        /// for a non-panicking alternative.
+        pub fn unsubscribe_mut(&mut self, subscriber: Rc<RefCell<dyn SubscriberMut<T>>>) {
+            if let Err(err) = self.try_unsubscribe_mut(subscriber) {
+                panic!("{err}");
+            }
+        }
+
+        /// Like [unsubscribe()](Event::unsubscribe()), but returns an error instead of panicking
+        /// when the subscriber is not subscribed to this event.
+        pub fn try_unsubscribe(&mut self, subscription: impl Into<Subscription<T>>) -> Result<(), RustventError> {
+            self.remove_cancelled();
+
+            let id = match subscription.into() {
+                Subscription::Id(id) => {
+                    self.unsubscribe_id(id)?;
+                    id
+                },
+                Subscription::Subscriber(subscriber) => {
+                    if let Some(index) = self.contains(subscriber.clone()) {
+                        self.subscribers.remove(index).id
+                    } else {
+                        let index = self.weak_subscribers
+                        .iter()
+                        .position(|sub| Weak::ptr_eq(&sub.subscriber, &Rc::downgrade(&subscriber)))
+                        .ok_or(RustventError::SubscriberNotFound)?;
+
+                        self.weak_subscribers.remove(index).id
+                    }
+                }
+            };
+
+            self.unsubscribed(id);
+            Ok(())
+        }
+
+        /// Like [unsubscribe_mut()](Event::unsubscribe_mut()), but returns an error instead of panicking
+        /// when the subscriber is not subscribed to this event.
+        pub fn try_unsubscribe_mut(&mut self, subscriber: Rc<RefCell<dyn SubscriberMut<T>>>) -> Result<(), RustventError> {
+            self.remove_cancelled();
+
+            let index = self.contains_mut(subscriber).ok_or(RustventError::SubscriberNotFound)?;
+            let id = self.subscribers_mut.remove(index).id;
+            self.unsubscribed(id);
+            Ok(())
+        }
+
+        /// Removes the subscriber if it is still subscribed to this event, and does nothing otherwise,
+        /// e.g. because it was already removed by [Clear] after a notification.
+        /// Returns whether a subscriber was removed.
+        pub fn unsubscribe_if_present(&mut self, subscription: impl Into<Subscription<T>>) -> bool {
+            self.try_unsubscribe(subscription).is_ok()
         }
 
         /// Get all [Subscribers](Subscriber) listening to this event.
-        pub fn get_subscribers(&self) -> &Vec<Rc<dyn Subscriber>> {
-            &self.subscribers
+        pub fn get_subscribers(&self) -> Vec<Rc<dyn Subscriber<T>>> {
+            self.subscribers
+            .iter()
+            .filter(|sub| sub.is_active())
+            .map(|sub| sub.subscriber.clone())
+            .chain(self.weak_subscribers.iter().filter_map(|sub| sub.subscriber.upgrade()))
+            .collect()
         }
 
         /// Get all [Mutable Subscribers](SubscriberMut) listening to this event.
-        pub fn get_subscribers_mut(&self) -> &Vec<Rc<RefCell<dyn SubscriberMut>>> {
-            &self.subscribers_mut
+        pub fn get_subscribers_mut(&self) -> Vec<Rc<RefCell<dyn SubscriberMut<T>>>> {
+            self.subscribers_mut
+            .iter()
+            .filter(|sub| sub.is_active())
+            .map(|sub| sub.subscriber.clone())
+            .collect()
+        }
+
+        /// Get all [Fallible Subscribers](TrySubscriber) listening to this event.
+        pub fn get_try_subscribers(&self) -> Vec<Rc<dyn TrySubscriber<T>>> {
+            self.try_subscribers
+            .iter()
+            .filter(|sub| sub.is_active())
+            .map(|sub| sub.subscriber.clone())
+            .collect()
         }
 
         /// Get all closures listening to this event.
-        pub fn get_fn_subscribers(&self) -> &Vec<Box<dyn Fn() -> ()>> {
-            &self.fn_subscribers
+        pub fn get_fn_subscribers(&self) -> Vec<&FnSubscriber<T>> {
+            self.fn_subscribers
+            .iter()
+            .filter(|func| func.is_active())
+            .map(|func| &func.subscriber)
+            .collect()
+        }
+
+        /// Notifies subscribers, handing each of them a reference to `args`.  Which subscribers are
+        /// notified is determined by the configuration values defined by the [EventConfig] of this event.
+        /// The returned [NotificationReport] lists the [TrySubscribers](TrySubscriber) that failed.
+        ///
+        /// Every subscriber is notified even if `args` is [Cancellable](crate::args::Cancellable);
+        /// use [notify_cancellable()](Event::notify_cancellable()) to let subscribers stop the notification.
+        ///
+        /// # Examples
+        /// ```
+        /// struct OrderSubscriber {}
+        /// impl Subscriber<u32> for OrderSubscriber {
+        ///     fn update_with(&self, order_id: &u32) {
+        ///         println!("Order {order_id} changed...");
+        ///     }
+        /// }
+        ///
+        /// let mut order_changed: Event<u32> = Event::default();
+        /// order_changed.subscribe(Rc::new(OrderSubscriber {}));
+        /// order_changed.notify_with(&42);
+        /// ```
+        pub fn notify_with(&mut self, args: &T) -> NotificationReport {
+            self.notify_until(args, self.config.subscribers_to_notify, |_| false)
+        }
+
+        /// Like [notify_with()](Event::notify_with()), but only notifies the subscribers in `selection`
+        /// instead of those selected by the [EventConfig] of this event. Subscribers are still cleared
+        /// as configured.
+        /// # Examples
+        /// ```
+        /// let mut order_placed: Event<u32> = Event::default();
+        /// order_placed.notify_selected_with(&42, Notify::SUBSCRIBERS | Notify::SUBSCRIBERS_MUT); // Closures are left out.
+        /// ```
+        pub fn notify_selected_with(&mut self, args: &T, selection: Notify) -> NotificationReport {
+            self.notify_until(args, selection, |_| false)
+        }
+
+        /// Notifies subscribers like [notify_with()](Event::notify_with()), but stops as soon as a subscriber
+        /// stops the propagation of `args`, e.g. by cancelling [EventArgs](crate::args::EventArgs). The remaining
+        /// subscribers are not notified, and the publisher reads the final state back from `args`. Args whose
+        /// propagation is already stopped are not handed to any subscriber.
+        ///
+        /// # Examples
+        /// ```
+        /// let mut closing: Event<EventArgs> = Event::default();
+        /// closing.subscribe_as_fn_with(|args| args.cancel());
+        /// closing.subscribe_as_fn_with(|_| println!("Never notified..."));
+        ///
+        /// let args = EventArgs::default();
+        /// closing.notify_cancellable(&args);
+        /// assert!(args.is_cancelled());
+        /// ```
+        pub fn notify_cancellable(&mut self, args: &T) -> NotificationReport where T: Cancellable {
+            self.notify_until(args, self.config.subscribers_to_notify, T::is_propagation_stopped)
+        }
+
+        /// Notifies the subscribers in `selection` until `stop` returns `true` for `args`.
+        fn notify_until(&mut self, args: &T, selection: Notify, stop: impl Fn(&T) -> bool) -> NotificationReport {
+            let _span = trace_span!(DEBUG, "notify", event = self.name()).entered();
+            trace_event!(debug, ?selection, "notify started");
+
+            self.remove_cancelled();
+            self.remove_dead();
+            self.prune_latencies();
+            let mut report = NotificationReport::default();
+
+            self.dispatch(args, selection, &stop, &mut report);
+
+            self.clear(self.config.clear_subscribers_after_notification);
+            self.stats.record_notification(&report);
+            trace::report(&report);
+            trace_event!(debug, ok = report.is_ok(), "notify completed");
+            report
+        }
+
+        /// The name of this event, see [EventConfig::name].
+        pub fn name(&self) -> &'static str {
+            self.config.name.unwrap_or(std::any::type_name::<T>())
+        }
+
+        /// Returns `id`, once the subscription of a subscriber of `kind` was traced.
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        fn subscribed(&self, id: SubscriptionId, kind: &'static str) -> SubscriptionId {
+            trace_event!(debug, event = self.name(), subscriber = ?id, kind, "subscribed");
+            id
+        }
+
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        fn unsubscribed(&self, id: SubscriptionId) {
+            trace_event!(debug, event = self.name(), subscriber = ?id, "unsubscribed");
+        }
+
+        /// Drops subscribers whose [SubscriptionGuard] has been dropped.
+        fn remove_cancelled(&mut self) {
+            self.subscribers.retain(|sub| sub.is_active());
+            self.subscribers_mut.retain(|sub| sub.is_active());
+            self.fn_subscribers.retain(|func| func.is_active());
+            self.try_subscribers.retain(|sub| sub.is_active());
+        }
+
+        /// Drops the latency histograms of subscribers that were unsubscribed since the previous notification.
+        fn prune_latencies(&self) {
+            if !self.config.record_latency { return; }
+
+            let subscribed = self.subscribers.iter().map(|sub| sub.id)
+            .chain(self.weak_subscribers.iter().map(|sub| sub.id))
+            .chain(self.subscribers_mut.iter().map(|sub| sub.id))
+            .chain(self.fn_subscribers.iter().map(|func| func.id))
+            .chain(self.try_subscribers.iter().map(|sub| sub.id));
+
+            let len = self.subscribers.len() + self.weak_subscribers.len() + self.subscribers_mut.len() + self.fn_subscribers.len() + self.try_subscribers.len();
+
+            self.stats.prune_latencies(len, subscribed);
+        }
+
+        /// Drops [weak subscribers](Event::subscribe_weak()) whose subscriber has been dropped.
+        fn remove_dead(&mut self) {
+            let before = self.weak_subscribers.len();
+            self.weak_subscribers.retain(|sub| sub.subscriber.strong_count() > 0);
+            self.stats.record_pruned(before - self.weak_subscribers.len());
+        }
+
+        fn unsubscribe_id(&mut self, id: SubscriptionId) -> Result<(), RustventError> {
+            if let Some(index) = self.subscribers.iter().position(|sub| sub.id == id) {
+                self.subscribers.remove(index);
+            } else if let Some(index) = self.subscribers_mut.iter().position(|sub| sub.id == id) {
+                self.subscribers_mut.remove(index);
+            } else if let Some(index) = self.fn_subscribers.iter().position(|func| func.id == id) {
+                self.fn_subscribers.remove(index);
+            } else if let Some(index) = self.try_subscribers.iter().position(|sub| sub.id == id) {
+                self.try_subscribers.remove(index);
+            } else if let Some(index) = self.weak_subscribers.iter().position(|sub| sub.id == id) {
+                self.weak_subscribers.remove(index);
+            } else {
+                return Err(RustventError::SubscriptionNotFound(id));
+            }
+
+            Ok(())
+        }
+
+        fn contains(&self, subscriber: Rc<dyn Subscriber<T>>) -> Option<usize> {
+            self.subscribers
+            .iter()
+            .position(|existing_sub| Rc::ptr_eq(&existing_sub.subscriber, &subscriber))
+        }
+
+        fn contains_mut(&self, subscriber: Rc<RefCell<dyn SubscriberMut<T>>>) -> Option<usize> {
+            self.subscribers_mut
+            .iter()
+            .position(|existing_sub| Rc::ptr_eq(&existing_sub.subscriber, &subscriber))
+        }
+
+        /// Notifies the subscribers in `selection` by descending priority and then in registration order.
+        /// Stops at the first failing [TrySubscriber] if the [ErrorPolicy] says so, or as soon as `stop`
+        /// returns `true` for `args`, which is checked before each subscriber is notified.
+        fn dispatch(&mut self, args: &T, selection: Notify, stop: &dyn Fn(&T) -> bool, report: &mut NotificationReport) {
+            let mut handlers: Vec<(DispatchOrder, Handler<'_, T>)> = Vec::new();
+
+            if selection.contains(Notify::SUBSCRIBERS) {
+                handlers.extend(self.subscribers.iter().filter(|sub| sub.is_active() && sub.accepts(args)).map(|sub| (sub.dispatch_order(), Handler::Subscriber(sub.subscriber.clone()))));
+                handlers.extend(self.weak_subscribers.iter().filter_map(|sub| Some((sub.dispatch_order(), Handler::Subscriber(sub.subscriber.upgrade()?)))));
+                handlers.extend(self.try_subscribers.iter().filter(|sub| sub.is_active()).map(|sub| (sub.dispatch_order(), Handler::Try(&sub.subscriber))));
+            }
+            if selection.contains(Notify::SUBSCRIBERS_MUT) {
+                handlers.extend(self.subscribers_mut.iter().filter(|sub| sub.is_active()).map(|sub| (sub.dispatch_order(), Handler::Mut(&sub.subscriber))));
+            }
+            if selection.contains(Notify::FN_SUBSCRIBERS) {
+                handlers.extend(self.fn_subscribers.iter().filter(|func| func.is_active()).map(|func| (func.dispatch_order(), Handler::Fn(&func.subscriber))));
+            }
+            handlers.sort_by_key(|(order, _)| *order);
+
+            let catch_panics = self.config.catch_panics;
+            let (mut subscribers_notified, mut subscribers_mut_notified, mut fn_subscribers_notified) = (false, false, false);
+            let mut notified = HashSet::new();
+
+            for ((_, id), handler) in handlers {
+                if stop(args) { break; }
+
+                notified.insert(id);
+                let _span = trace_span!(TRACE, "subscriber", subscriber = ?id).entered();
+                let started = Instant::now();
+                let keep_notifying = match handler {
+                    Handler::Subscriber(sub) => {
+                        subscribers_notified = true;
+                        invoke(catch_panics, id, report, || sub.update_with(args));
+                        true
+                    },
+                    Handler::Try(sub) => {
+                        subscribers_notified = true;
+                        match invoke(catch_panics, id, report, || sub.try_update_with(args)) {
+                            Some(Err(error)) => self.config.on_subscriber_error.handle(report, id, error),
+                            _ => true,
+                        }
+                    },
+                    Handler::Mut(sub) => {
+                        subscribers_mut_notified = true;
+                        invoke(catch_panics, id, report, || sub.borrow_mut().update_mut_with(args));
+                        true
+                    },
+                    Handler::Fn(func) => {
+                        fn_subscribers_notified = true;
+                        invoke(catch_panics, id, report, || func(args));
+                        true
+                    },
+                };
+
+                let elapsed = started.elapsed();
+                trace_event!(trace, ?elapsed, "subscriber notified");
+                if self.config.record_latency { self.stats.record_latency(id, elapsed); }
+                self.config.check_latency(report, id, elapsed);
+
+                if !keep_notifying { break; }
+            }
+
+            self.stats.record_invocations(notified.len());
+            if subscribers_notified { self.stats.record_subscribers_notified(); }
+            if subscribers_mut_notified { self.stats.record_subscribers_mut_notified(); }
+            if fn_subscribers_notified { self.stats.record_fn_subscribers_notified(); }
+
+            remove_exhausted(&mut self.subscribers, &notified);
+            remove_exhausted(&mut self.weak_subscribers, &notified);
+            remove_exhausted(&mut self.try_subscribers, &notified);
+            remove_exhausted(&mut self.subscribers_mut, &notified);
+            remove_exhausted(&mut self.fn_subscribers, &notified);
+        }
+
+        /// A snapshot of the statistics of this event.
+        pub fn stats(&self) -> EventStats {
+            self.stats.snapshot()
+        }
+
+        /// Sets every statistic of this event back to zero and drops the latency histograms of its subscribers.
+        pub fn reset_stats(&self) {
+            self.stats.reset();
+        }
+
+        /// How long the invocations of the subscriber `id` took, or `None` if it was not notified since it
+        /// subscribed or the statistics of this event were reset. Latency is only recorded with
+        /// [EventConfig::record_latency].
+        pub fn latency(&self, id: SubscriptionId) -> Option<LatencyHistogram> {
+            self.stats.latency(id)
+        }
+
+        /// The latency histograms of the notified subscribers, starting with the subscriber this event spent
+        /// the most time in. Histograms of unsubscribed subscribers may be dropped on the next notification.
+        /// # Examples
+        /// ```
+        /// let report = document_saved.notify_with(&document);
+        /// if let Some((id, latency)) = document_saved.latencies().first() {
+        ///     println!("Subscriber {id:?} took {:?}", latency.total());
+        /// }
+        /// ```
+        pub fn latencies(&self) -> Vec<(SubscriptionId, LatencyHistogram)> {
+            self.stats.latencies_snapshot()
+        }
+
+        /// Unsubscribes every subscriber in `selection`, as the event does after each notification
+        /// according to its [EventConfig].
+        /// # Examples
+        /// ```
+        /// some_event.clear(Clear::FN_SUBSCRIBERS | Clear::SUBSCRIBERS_MUT);
+        /// ```
+        pub fn clear(&mut self, selection: Clear) {
+            if !selection.is_empty() {
+                trace_event!(debug, event = self.name(), ?selection, "cleared");
+            }
+
+            if selection.contains(Clear::SUBSCRIBERS) {
+                self.subscribers.clear();
+                self.weak_subscribers.clear();
+                self.try_subscribers.clear();
+            }
+            if selection.contains(Clear::SUBSCRIBERS_MUT) {
+                self.subscribers_mut.clear();
+            }
+            if selection.contains(Clear::FN_SUBSCRIBERS) {
+                self.fn_subscribers.clear();
+            }
+        }
+    }
+
+    impl Event<()> {
+        /// Subscribe to an event with a closure.
+        /// Need to subscribe to an event with a Struct? See [subscribe()](Event::subscribe()).
+        /// # Examples
+        /// ```
+        /// let mut some_event = Event::default();
+        /// some_event.subscribe_as_fn(|| println!("Closure notified..."));
+        /// ```
+        pub fn subscribe_as_fn<F>(&mut self, func: F) -> SubscriptionId where F: Fn() + 'static {
+            self.subscribe_as_fn_with(move |_| func())
         }
 
         /// Notifies subscribers.  Which subscribers are notified is determined by the configuration values
@@ -171,104 +926,125 @@ pub mod events {
         /// // NewSubscriber has now subscribed to the process_completed `Event`.
         /// logic.process_completed += rc_new_sub.clone(); 
         /// ```
-        pub fn notify(&mut self) {
-            match self.config.subscribers_to_notify {
-                Notify::All => {
-                    self.notify_subscribers();
-                    self.notify_subscribers_mut();
-                    self.notify_fn_subscribers();
-                },
-                Notify::OnlySubscribers => self.notify_subscribers(),
-                Notify::OnlySubscribersMut => self.notify_subscribers_mut(),
-                Notify::OnlyFnSubscribers => self.notify_fn_subscribers(),
-            }
-
-            self.try_clear();
+        pub fn notify(&mut self) -> NotificationReport {
+            self.notify_with(&())
         }
 
-        fn contains(&self, subscriber: Rc<dyn Subscriber>) -> Option<usize> {
-            if let Some(i) = self.subscribers
-            .iter()
-            .position(|existing_sub| Rc::ptr_eq(existing_sub, &subscriber))
-            {
-                Some(i)
-            } else {
-                None
-            }
+        /// Like [notify()](Event::notify()), see [notify_selected_with()](Event::notify_selected_with()).
+        pub fn notify_selected(&mut self, selection: Notify) -> NotificationReport {
+            self.notify_selected_with(&(), selection)
         }
+    }
 
-        fn contains_mut(&self, subscriber: Rc<RefCell<dyn SubscriberMut>>) -> Option<usize> {
-            if let Some(i) = self.subscribers_mut
-            .iter()
-            .position(|existing_sub| Rc::ptr_eq(existing_sub, &subscriber))
-            {
-                Some(i)
-            } else {
-                None
-            }
+    impl<T> Default for Event<T> {
+        fn default() -> Self {
+            Self::new(EventConfig::default())
         }
+    }
 
-        fn notify_subscribers(&mut self) {
-            if self.subscribers.is_empty() { return; }
+    impl<T> From<SubscriptionId> for Subscription<T> {
+        fn from(id: SubscriptionId) -> Self {
+            Subscription::Id(id)
+        }
+    }
 
-            for sub in self.subscribers.iter() {
-                sub.update();
-            }
-            self.times_subscribers_notified += 1;
+    impl<T> From<Rc<dyn Subscriber<T>>> for Subscription<T> {
+        fn from(subscriber: Rc<dyn Subscriber<T>>) -> Self {
+            Subscription::Subscriber(subscriber)
         }
+    }
 
-        fn notify_subscribers_mut(&mut self) {
-            if self.subscribers_mut.is_empty() { return; }
+    impl<T, S> From<Rc<S>> for Subscription<T> where S: Subscriber<T> + 'static {
+        fn from(subscriber: Rc<S>) -> Self {
+            Subscription::Subscriber(subscriber)
+        }
+    }
 
-            for sub in self.subscribers_mut.iter_mut() {
-                // let sub_mut = Rc::get_mut(sub).expect("The current subscriber is shared and cannot be safely mutated.");
-                sub.borrow_mut().update_mut();
-            }
-            self.times_subscribers_mut_notified += 1;
+    impl<T> SubAssign<SubscriptionId> for Event<T> {
+        fn sub_assign(&mut self, rhs: SubscriptionId) {
+            self.unsubscribe_if_present(rhs);
         }
+    }
 
-        fn notify_fn_subscribers(&mut self) {
-            if self.fn_subscribers.is_empty() { return; }
+    /// A subscriber of any kind, borrowed from an [Event] for the duration of a notification.
+    enum Handler<'a, T> {
+        Subscriber(Rc<dyn Subscriber<T>>),
+        Try(&'a Rc<dyn TrySubscriber<T>>),
+        Mut(&'a Rc<RefCell<dyn SubscriberMut<T>>>),
+        Fn(&'a FnSubscriber<T>),
+    }
 
-            for func in self.fn_subscribers.iter() {
-                func();
-            }
-            self.times_func_subscribers_notified += 1;
+    /// Invokes the subscriber `id` through `handler`. When `catch_panics` is set, a panic is recorded in
+    /// `report` and `None` is returned, instead of unwinding out of the notification.
+    pub(crate) fn invoke<R>(catch_panics: bool, id: SubscriptionId, report: &mut NotificationReport, handler: impl FnOnce() -> R) -> Option<R> {
+        if !catch_panics {
+            return Some(handler());
         }
 
-        fn try_clear(&mut self) {
-            match self.config.clear_subscribers_after_notification {
-                Clear::All => self.clear_all_subscribers(),
-                Clear::OnlySubscribers => self.clear_subscribers(),
-                Clear::OnlySubscribersMut => self.clear_subscribers_mut(),
-                Clear::OnlyFuncSubscribers => self.clear_fn_subscribers(),
-                Clear::None => return,
+        match panic::catch_unwind(AssertUnwindSafe(handler)) {
+            Ok(result) => Some(result),
+            Err(payload) => {
+                report.push_panic(id, payload);
+                None
             }
         }
-        
-        fn clear_all_subscribers(&mut self) {
-            self.clear_subscribers();
-            self.clear_fn_subscribers();
-        }
-    
-        fn clear_subscribers(&mut self) {
-            self.subscribers.clear();
-        }
+    }
 
-        fn clear_subscribers_mut(&mut self) {
-            self.subscribers_mut.clear();
+    impl ErrorPolicy {
+        /// Handles the `error` returned by the subscriber `id` according to this policy.
+        /// Returns whether the remaining subscribers should still be notified.
+        pub(crate) fn handle(&self, report: &mut NotificationReport, id: SubscriptionId, error: SubscriberError) -> bool {
+            match self {
+                ErrorPolicy::StopAtFirst => {
+                    report.push_failure(id, error);
+                    false
+                },
+                ErrorPolicy::CollectAll => {
+                    report.push_failure(id, error);
+                    true
+                },
+                ErrorPolicy::LogAndIgnore => {
+                    trace_event!(warn, subscriber = ?id, error = %error, "subscriber failed");
+                    report.push_ignored_failure();
+                    true
+                },
+            }
         }
-        
-        fn clear_fn_subscribers(&mut self) {
-            self.fn_subscribers.clear();
+    }
+
+    impl EventConfig {
+        /// Hands the subscriber `id` to the [SlowHandlerPolicy] of this configuration if it took
+        /// at least the [slow_handler_threshold](EventConfig::slow_handler_threshold) to be notified.
+        pub(crate) fn check_latency(&self, report: &mut NotificationReport, id: SubscriptionId, elapsed: Duration) {
+            if self.slow_handler_threshold.is_none_or(|threshold| elapsed < threshold) {
+                return;
+            }
+
+            match &self.on_slow_handler {
+                SlowHandlerPolicy::Report => report.push_slow_handler(id, elapsed),
+                SlowHandlerPolicy::Log => {
+                    trace_event!(warn, subscriber = ?id, ?elapsed, "subscriber was slow");
+                },
+                SlowHandlerPolicy::Callback(callback) => callback(id, elapsed),
+            }
         }
     }
 
     impl Default for EventConfig {
         fn default() -> Self {
             Self { 
+                name: None,
                 subscribers_to_notify: Notify::All, 
-                clear_subscribers_after_notification: Clear::All 
+                clear_subscribers_after_notification: Clear::All,
+                on_subscriber_error: ErrorPolicy::CollectAll,
+                on_poisoned_subscriber: PoisonPolicy::Evict,
+                catch_panics: false,
+                dispatch: Dispatch::ThreadPerSubscriber,
+                await_subscribers: Await::Concurrently,
+                reentrancy: Reentrancy::Queue,
+                slow_handler_threshold: None,
+                record_latency: false,
+                on_slow_handler: SlowHandlerPolicy::Report,
             }
         }
     }
@@ -369,7 +1145,7 @@ mod tests {
         event.subscribe(rc_sub.clone());
         event.notify();
 
-        assert_eq!(1, event.times_subscribers_notified);
+        assert_eq!(1, event.stats().subscribers_notified);
     }
 
     #[test]
@@ -381,7 +1157,7 @@ mod tests {
         event.subscribe_mut(rc_sub.clone());
         event.notify();
 
-        assert_eq!(1, event.times_subscribers_mut_notified);
+        assert_eq!(1, event.stats().subscribers_mut_notified);
         assert_eq!(20, rc_sub.borrow().mutate_field_int);
     }
 
@@ -441,4 +1217,415 @@ mod tests {
         some_event.subscribe_as_fn(|| println!("Closure: run some logic..."));
         some_event.notify();
     }
+
+    #[test]
+    fn event_typed_subscriber_receives_payload() {
+        struct OrderSubscriber {
+            last_order_id: std::cell::Cell<u32>
+        }
+
+        impl Subscriber<u32> for OrderSubscriber {
+            fn update_with(&self, order_id: &u32) {
+                self.last_order_id.set(*order_id);
+            }
+        }
+
+        let mut order_changed: Event<u32> = Event::default();
+        let sub = into_subscriber!(OrderSubscriber { last_order_id: Default::default() });
+
+        order_changed.subscribe(sub.clone());
+        order_changed.notify_with(&42);
+
+        assert_eq!(42, sub.last_order_id.get());
+        assert_eq!(1, order_changed.stats().subscribers_notified);
+    }
+
+    #[test]
+    fn event_typed_subscriber_mut_receives_payload() {
+        struct OrderTotal {
+            total: u32
+        }
+
+        impl SubscriberMut<u32> for OrderTotal {
+            fn update_mut_with(&mut self, amount: &u32) {
+                self.total += amount;
+            }
+        }
+
+        let config = events::EventConfig { 
+            subscribers_to_notify: events::Notify::All, 
+            clear_subscribers_after_notification: events::Clear::None,
+            ..Default::default()
+        };
+        let mut order_placed: Event<u32> = Event::new(config);
+        let sub = into_mut_subscriber!(OrderTotal { total: 0 });
+
+        order_placed.subscribe_mut(sub.clone());
+        order_placed.notify_with(&15);
+        order_placed.notify_with(&5);
+
+        assert_eq!(20, sub.borrow().total);
+    }
+
+    #[test]
+    fn event_typed_fn_subscriber_receives_payload() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+
+        let mut order_changed: Event<String> = Event::default();
+        order_changed.subscribe_as_fn_with(move |order_id: &String| received_clone.borrow_mut().push(order_id.clone()));
+        order_changed.notify_with(&"A-100".to_string());
+
+        assert_eq!(vec!["A-100".to_string()], *received.borrow());
+    }
+
+    #[test]
+    fn event_fn_subscriber_can_unsubscribe_by_id() {
+        let mut some_event = Event::default();
+        let first = some_event.subscribe_as_fn(|| println!("First closure..."));
+        some_event.subscribe_as_fn(|| println!("Second closure..."));
+
+        some_event.unsubscribe(first);
+
+        assert_eq!(1, some_event.get_fn_subscribers().len());
+    }
+
+    #[test]
+    fn event_subscribers_of_every_kind_can_unsubscribe_by_id() {
+        let mut event = Event::default();
+        let sub_id = event.subscribe(into_subscriber!(SomeSubscriber {}));
+        let mut_sub_id = event.subscribe_mut(into_mut_subscriber!(MutSubscriber { mutate_field_int: 10 }));
+
+        event.unsubscribe(sub_id);
+        event -= mut_sub_id;
+
+        assert!(event.get_subscribers().is_empty());
+        assert!(event.get_subscribers_mut().is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "argument could not be found in the list of subscribers")]
+    fn event_panics_if_subscription_id_belongs_to_another_event() {
+        let mut some_event: Event = Event::default();
+        let mut another_event = Event::default();
+        let id = another_event.subscribe_as_fn(|| println!("Closure notified..."));
+
+        some_event.unsubscribe(id);
+    }
+
+    #[test]
+    fn event_scoped_subscriber_is_removed_when_guard_is_dropped() {
+        let config = events::EventConfig { 
+            subscribers_to_notify: events::Notify::All, 
+            clear_subscribers_after_notification: events::Clear::None,
+            ..Default::default()
+        };
+        let mut event = Event::new(config);
+        let rc_sub = into_mut_subscriber!(MutSubscriber { mutate_field_int: 10 });
+
+        let guard = event.subscribe_mut_scoped(rc_sub.clone());
+        event.notify();
+        drop(guard);
+        event.notify();
+
+        assert_eq!(20, rc_sub.borrow().mutate_field_int);
+        assert!(event.get_subscribers_mut().is_empty());
+        assert_eq!(1, Rc::strong_count(&rc_sub));
+    }
+
+    #[test]
+    fn event_scoped_closure_is_notified_while_guard_is_alive() {
+        let calls = Rc::new(std::cell::Cell::new(0));
+        let calls_clone = calls.clone();
+        let config = events::EventConfig { 
+            subscribers_to_notify: events::Notify::All, 
+            clear_subscribers_after_notification: events::Clear::None,
+            ..Default::default()
+        };
+        let mut event = Event::new(config);
+
+        let _guard = event.subscribe_as_fn_scoped(move |_| calls_clone.set(calls_clone.get() + 1));
+        event.notify();
+        event.notify();
+
+        assert_eq!(2, calls.get());
+    }
+
+    #[test]
+    fn event_try_unsubscribe_returns_error_if_type_did_not_subscribe() {
+        let mut event = Event::default();
+        let sub = into_subscriber!(SomeSubscriber {});
+        let mut_sub = into_mut_subscriber!(MutSubscriber { mutate_field_int: 10 });
+
+        assert_eq!(Err(RustventError::SubscriberNotFound), event.try_unsubscribe(sub.clone()));
+        assert_eq!(Err(RustventError::SubscriberNotFound), event.try_unsubscribe_mut(mut_sub.clone()));
+    }
+
+    #[test]
+    fn event_try_unsubscribe_returns_error_for_subscription_removed_by_clear() {
+        let mut event = Event::default();
+        let id = event.subscribe(into_subscriber!(SomeSubscriber {}));
+
+        event.notify();
+
+        assert_eq!(Err(RustventError::SubscriptionNotFound(id)), event.try_unsubscribe(id));
+    }
+
+    #[test]
+    fn event_unsubscribe_if_present_is_idempotent() {
+        let mut event = Event::default();
+        let id = event.subscribe_as_fn(|| println!("Closure notified..."));
+
+        assert!(event.unsubscribe_if_present(id));
+        assert!(!event.unsubscribe_if_present(id));
+
+        event -= id;
+        assert!(event.get_fn_subscribers().is_empty());
+    }
+
+    struct FailingSubscriber {
+        calls: std::cell::Cell<u8>
+    }
+
+    impl TrySubscriber for FailingSubscriber {
+        fn try_update(&self) -> Result<(), SubscriberError> {
+            self.calls.set(self.calls.get() + 1);
+            Err("subscriber failed".into())
+        }
+    }
+
+    fn event_with_error_policy(policy: events::ErrorPolicy) -> Event {
+        Event::new(events::EventConfig { 
+            on_subscriber_error: policy,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn event_try_subscribers_errors_are_collected() {
+        let mut event = event_with_error_policy(events::ErrorPolicy::CollectAll);
+        let first = event.subscribe_try(into_subscriber!(FailingSubscriber { calls: Default::default() }));
+        let second = event.subscribe_try(into_subscriber!(FailingSubscriber { calls: Default::default() }));
+
+        let report = event.notify();
+
+        assert!(!report.is_ok());
+        assert_eq!(vec![first, second], report.failures().iter().map(|f| f.id).collect::<Vec<_>>());
+        assert_eq!("subscriber failed", report.failures()[0].error.to_string());
+    }
+
+    #[test]
+    fn event_try_subscribers_stop_at_first_error() {
+        let mut event = event_with_error_policy(events::ErrorPolicy::StopAtFirst);
+        let second_sub = into_subscriber!(FailingSubscriber { calls: Default::default() });
+        let calls = Rc::new(std::cell::Cell::new(0));
+        let calls_clone = calls.clone();
+
+        event.subscribe_try(into_subscriber!(FailingSubscriber { calls: Default::default() }));
+        event.subscribe_try(second_sub.clone());
+        event.subscribe_as_fn(move || calls_clone.set(calls_clone.get() + 1));
+
+        let report = event.notify();
+
+        assert_eq!(1, report.failures().len());
+        assert_eq!(0, second_sub.calls.get());
+        assert_eq!(0, calls.get());
+    }
+
+    #[test]
+    fn event_try_subscribers_errors_can_be_ignored() {
+        let mut event = event_with_error_policy(events::ErrorPolicy::LogAndIgnore);
+        let sub = into_subscriber!(FailingSubscriber { calls: Default::default() });
+
+        event.subscribe_try(sub.clone());
+        let report = event.notify();
+
+        assert!(report.is_ok());
+        assert_eq!(1, sub.calls.get());
+        assert_eq!(1, event.stats().failures);
+    }
+
+    #[test]
+    fn event_catching_panics_keeps_notifying_remaining_subscribers() {
+        let mut event = Event::new(events::EventConfig { catch_panics: true, ..Default::default() });
+        let calls = Rc::new(std::cell::Cell::new(0));
+        let calls_clone = calls.clone();
+
+        let id = event.subscribe_as_fn(|| panic!("Closure failed"));
+        event.subscribe_as_fn(move || calls_clone.set(calls_clone.get() + 1));
+        let report = event.notify();
+
+        assert_eq!(1, calls.get());
+        assert_eq!(id, report.panics()[0].id);
+        assert_eq!("Closure failed", report.panics()[0].message);
+        assert_eq!(1, event.stats().fn_subscribers_notified);
+        assert!(event.get_fn_subscribers().is_empty());
+    }
+
+    #[test]
+    fn event_catching_panics_leaves_subscriber_mut_usable() {
+        struct PanickingMutSubscriber {
+            mutate_field_int: u8
+        }
+
+        impl SubscriberMut for PanickingMutSubscriber {
+            fn update_mut(&mut self) {
+                self.mutate_field_int += 10;
+                panic!("PanickingMutSubscriber failed");
+            }
+        }
+
+        let mut event = Event::new(events::EventConfig { catch_panics: true, ..Default::default() });
+        let rc_sub = into_mut_subscriber!(PanickingMutSubscriber { mutate_field_int: 10 });
+
+        event.subscribe_mut(rc_sub.clone());
+        let report = event.notify();
+
+        assert_eq!(1, report.panics().len());
+        assert_eq!(1, event.stats().subscribers_mut_notified);
+        assert_eq!(20, rc_sub.borrow_mut().mutate_field_int);
+    }
+
+    /// Records the name it was given in a shared log when notified.
+    struct LoggingSubscriber {
+        name: &'static str,
+        log: Rc<RefCell<Vec<&'static str>>>
+    }
+
+    impl Subscriber for LoggingSubscriber {
+        fn update(&self) {
+            self.log.borrow_mut().push(self.name);
+        }
+    }
+
+    impl SubscriberMut for LoggingSubscriber {
+        fn update_mut(&mut self) {
+            self.log.borrow_mut().push(self.name);
+        }
+    }
+
+    #[test]
+    fn event_notifies_subscribers_by_priority_across_kinds() {
+        let mut event = Event::default();
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let persistence = log.clone();
+        event.subscribe_as_fn_with_priority(move |_| persistence.borrow_mut().push("persistence"), 0);
+        event.subscribe_mut_with_priority(into_mut_subscriber!(LoggingSubscriber { name: "audit", log: log.clone() }), -5);
+        event.subscribe_with_priority(into_subscriber!(LoggingSubscriber { name: "validation", log: log.clone() }), 10);
+        event.notify();
+
+        assert_eq!(vec!["validation", "persistence", "audit"], *log.borrow());
+    }
+
+    #[test]
+    fn event_notifies_subscribers_of_equal_priority_in_registration_order_after_unsubscribe() {
+        let mut event = Event::new(events::EventConfig { clear_subscribers_after_notification: events::Clear::None, ..Default::default() });
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let first = event.subscribe(into_subscriber!(LoggingSubscriber { name: "first", log: log.clone() }));
+        event.subscribe_mut(into_mut_subscriber!(LoggingSubscriber { name: "second", log: log.clone() }));
+        event.subscribe(into_subscriber!(LoggingSubscriber { name: "third", log: log.clone() }));
+        event.subscribe(into_subscriber!(LoggingSubscriber { name: "fourth", log: log.clone() }));
+
+        event.unsubscribe(first);
+        event.notify();
+
+        assert_eq!(vec!["second", "third", "fourth"], *log.borrow());
+    }
+
+    #[test]
+    fn event_try_subscribers_stop_at_first_error_skips_lower_priorities() {
+        let mut event = event_with_error_policy(events::ErrorPolicy::StopAtFirst);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        event.subscribe_with_priority(into_subscriber!(LoggingSubscriber { name: "before", log: log.clone() }), 2);
+        let failing = event.subscribe_try_with_priority(into_subscriber!(FailingSubscriber { calls: Default::default() }), 1);
+        event.subscribe_mut(into_mut_subscriber!(LoggingSubscriber { name: "after", log: log.clone() }));
+        let report = event.notify();
+
+        assert_eq!(failing, report.failures()[0].id);
+        assert_eq!(vec!["before"], *log.borrow());
+        assert_eq!(0, event.stats().subscribers_mut_notified);
+    }
+
+    #[test]
+    fn event_cancelled_args_stop_notifying_lower_priorities() {
+        let mut closing: Event<args::EventArgs<&'static str>> = Event::default();
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let editor = log.clone();
+        closing.subscribe_as_fn_with_priority(move |args| {
+            editor.borrow_mut().push("editor");
+            if args.data == "unsaved" { args.cancel(); }
+        }, 10);
+        let window = log.clone();
+        closing.subscribe_as_fn_with(move |_| window.borrow_mut().push("window"));
+
+        let args = args::EventArgs::new("unsaved");
+        closing.notify_cancellable(&args);
+
+        assert!(args.is_cancelled());
+        assert!(!args.is_handled());
+        assert_eq!(vec!["editor"], *log.borrow());
+    }
+
+    #[test]
+    fn event_already_cancelled_args_are_not_handed_to_subscribers() {
+        let mut closing: Event<args::EventArgs> = Event::default();
+        let calls = Rc::new(std::cell::Cell::new(0));
+        let calls_clone = calls.clone();
+        closing.subscribe_as_fn_with(move |_| calls_clone.set(calls_clone.get() + 1));
+
+        let args = args::EventArgs::default();
+        args.cancel();
+        closing.notify_cancellable(&args);
+
+        assert_eq!(0, calls.get());
+    }
+
+    #[test]
+    fn event_args_are_ignored_unless_notified_as_cancellable() {
+        let mut event: Event<args::EventArgs> = Event::new(events::EventConfig { clear_subscribers_after_notification: events::Clear::None, ..Default::default() });
+        let calls = Rc::new(std::cell::Cell::new(0));
+        let calls_clone = calls.clone();
+
+        event.subscribe_as_fn_with(|args| args.set_handled());
+        event.subscribe_as_fn_with(move |_| calls_clone.set(calls_clone.get() + 1));
+
+        event.notify_with(&args::EventArgs::default());
+        let args = args::EventArgs::default();
+        event.notify_cancellable(&args);
+
+        assert!(args.is_handled());
+        assert_eq!(1, calls.get());
+    }
+
+    #[test]
+    fn event_weak_subscriber_owning_the_event_does_not_leak() {
+        struct Window {
+            closed: RefCell<Event>,
+            drops: Rc<std::cell::Cell<u8>>
+        }
+
+        impl Subscriber for Window {}
+
+        impl Drop for Window {
+            fn drop(&mut self) {
+                self.drops.set(self.drops.get() + 1);
+            }
+        }
+
+        let drops = Rc::new(std::cell::Cell::new(0));
+        let window = Rc::new(Window { closed: RefCell::new(Event::default()), drops: drops.clone() });
+        let weak_window: std::rc::Weak<dyn Subscriber> = Rc::downgrade(&window) as std::rc::Weak<Window>;
+
+        window.closed.borrow_mut().subscribe_weak(weak_window);
+        assert_eq!(1, window.closed.borrow().get_subscribers().len());
+
+        drop(window);
+
+        assert_eq!(1, drops.get());
+    }
 }
+
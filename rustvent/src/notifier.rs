@@ -0,0 +1,54 @@
+use crate::{
+    events::Event,
+    event_async::EventAsync,
+    reentrant::ReentrantEvent,
+    report::NotificationReport,
+    error::RustventError
+};
+
+/// Notifies the subscribers of any kind of event the same way. The `on_<field>` methods generated by
+/// `#[derive(Event)]` call it, so they work for every field whose type implements it, including through
+/// a type alias.
+/// # Examples
+/// ```
+/// fn notify_twice<N: Notifier<Args = u32>>(event: &mut N) {
+///     event.notify_with(&1);
+///     event.notify_with(&2);
+/// }
+/// ```
+pub trait Notifier {
+    /// The payload handed to subscribers.
+    type Args;
+    /// What notifying the event returns.
+    type Output;
+
+    fn notify_with(&mut self, args: &Self::Args) -> Self::Output;
+}
+
+impl<T> Notifier for Event<T> {
+    type Args = T;
+    type Output = NotificationReport;
+
+    fn notify_with(&mut self, args: &T) -> NotificationReport {
+        Event::notify_with(self, args)
+    }
+}
+
+impl<T: Sync> Notifier for EventAsync<T> {
+    type Args = T;
+    type Output = NotificationReport;
+
+    fn notify_with(&mut self, args: &T) -> NotificationReport {
+        EventAsync::notify_with(self, args)
+    }
+}
+
+/// A [ReentrantEvent] takes its payload by value, so it is cloned.
+impl<T: Clone> Notifier for ReentrantEvent<T> {
+    type Args = T;
+    type Output = Result<NotificationReport, RustventError>;
+
+    fn notify_with(&mut self, args: &T) -> Result<NotificationReport, RustventError> {
+        ReentrantEvent::notify_with(self, args.clone())
+    }
+}
@@ -0,0 +1,148 @@
+use std::{mem, panic::{self, AssertUnwindSafe}, sync::{mpsc, Arc, Condvar, Mutex}, thread::{self, JoinHandle}};
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A fixed number of worker threads that [EventAsyncs](crate::event_async::EventAsync) notify their
+/// subscribers on, instead of spawning a thread per subscriber for every notification. Configure an event
+/// to use it with [Dispatch::Pool](crate::events::Dispatch::Pool). Wrapped in an `Arc`, the same pool
+/// may be shared between several events.
+///
+/// A subscriber must not notify an event using the same pool from inside its `update`, since it
+/// would wait for workers that may all be busy waiting themselves.
+/// # Examples
+/// ```
+/// let pool = Arc::new(ThreadPool::new(4));
+/// let config = EventConfig { dispatch: Dispatch::Pool(pool.clone()), ..Default::default() };
+///
+/// let mut order_placed: EventAsync<u32> = EventAsync::new(config);
+/// ```
+pub struct ThreadPool {
+    sender: Option<mpsc::Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+/// Counts the jobs of a [ThreadPool::run_all()] call that have not completed yet.
+struct Pending {
+    count: Mutex<usize>,
+    completed: Condvar,
+}
+
+/// Blocks until every pending job completed when dropped, even while unwinding.
+struct WaitGuard<'a>(&'a Pending);
+
+impl ThreadPool {
+    /// Creates a pool with `workers` threads.
+    ///
+    /// # Panics
+    /// Panics if `workers` is zero.
+    pub fn new(workers: usize) -> ThreadPool {
+        assert!(workers > 0, "A ThreadPool needs at least one worker.");
+
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let workers = (0..workers)
+        .map(|i| {
+            let receiver = receiver.clone();
+
+            thread::Builder::new()
+            .name(format!("rustvent-worker-{i}"))
+            .spawn(move || loop {
+                let job = receiver.lock().unwrap().recv();
+                match job {
+                    Ok(job) => job(),
+                    Err(_) => break,
+                }
+            })
+            .expect("Failed to spawn a ThreadPool worker.")
+        })
+        .collect();
+
+        ThreadPool { sender: Some(sender), workers }
+    }
+
+    /// The number of worker threads of this pool.
+    pub fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Runs every job on the workers of this pool and blocks until all of them completed.
+    /// Results are returned in the order of `jobs`, with the payload of the jobs that panicked.
+    pub(crate) fn run_all<'env, R>(&self, jobs: Vec<Box<dyn FnOnce() -> R + Send + 'env>>) -> Vec<thread::Result<R>>
+    where R: Send + 'static {
+        let len = jobs.len();
+        let pending = Arc::new(Pending { count: Mutex::new(0), completed: Condvar::new() });
+        let (result_sender, result_receiver) = mpsc::channel();
+
+        {
+            let _wait = WaitGuard(&pending);
+
+            for (index, job) in jobs.into_iter().enumerate() {
+                let result_sender = result_sender.clone();
+                let job_pending = pending.clone();
+
+                let job: Box<dyn FnOnce() + Send + 'env> = Box::new(move || {
+                    let result = panic::catch_unwind(AssertUnwindSafe(job));
+                    let _ = result_sender.send((index, result));
+                    job_pending.complete();
+                });
+
+                // SAFETY: the job only borrows data living for 'env. `_wait` blocks until the job completed,
+                // even if this function unwinds, and the job no longer uses anything borrowed for 'env once
+                // it calls `complete()`, so the borrows never outlive this call.
+                let job = unsafe { mem::transmute::<Box<dyn FnOnce() + Send + 'env>, Job>(job) };
+
+                pending.add();
+                if let Err(mpsc::SendError(job)) = self.sender.as_ref().unwrap().send(job) {
+                    drop(job);
+                    pending.complete();
+                    panic!("The ThreadPool workers have stopped.");
+                }
+            }
+        }
+
+        drop(result_sender);
+
+        let mut results: Vec<_> = result_receiver.into_iter().collect();
+        results.sort_by_key(|(index, _)| *index);
+        debug_assert_eq!(len, results.len());
+
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        drop(self.sender.take());
+
+        for worker in self.workers.drain(..) {
+            // The last reference to a shared pool may be dropped by a job running on one of its workers.
+            if worker.thread().id() != thread::current().id() {
+                let _ = worker.join();
+            }
+        }
+    }
+}
+
+impl Pending {
+    fn add(&self) {
+        *self.count.lock().unwrap() += 1;
+    }
+
+    fn complete(&self) {
+        let mut count = self.count.lock().unwrap();
+        *count -= 1;
+        if *count == 0 {
+            self.completed.notify_all();
+        }
+    }
+}
+
+impl Drop for WaitGuard<'_> {
+    fn drop(&mut self) {
+        let mut count = self.0.count.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        while *count > 0 {
+            count = self.0.completed.wait(count).unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+}
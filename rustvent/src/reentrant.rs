@@ -0,0 +1,290 @@
+use std::{cell::{Cell, RefCell}, collections::VecDeque, rc::Rc};
+
+use crate::{
+    subscriber::{Subscriber, SubscriberMut},
+    subscription::{DispatchOrder, Registered, SubscriptionId},
+    error::RustventError,
+    events::{invoke, EventConfig, Notify, Clear, Reentrancy},
+    report::NotificationReport
+};
+
+/// A **synchronous** event that subscribers may modify and notify from inside their own `update`.
+///
+/// Unlike an [Event](crate::events::Event), every method takes `&self`. Subscribing or unsubscribing while the
+/// event is notifying its subscribers is deferred until the notification completed, so the subscribers being
+/// notified are not affected. Notifying the event from one of its subscribers is handled according to the
+/// [Reentrancy] of the event: the notification is either queued or made right away, up to a maximum depth.
+/// # Examples
+/// ```
+/// let retried: Rc<ReentrantEvent<u32>> = Rc::new(ReentrantEvent::default());
+///
+/// let event = Rc::downgrade(&retried);
+/// retried.subscribe_as_fn_with(move |attempt| if *attempt < 3 {
+///     event.upgrade().unwrap().notify_with(attempt + 1).unwrap();
+/// });
+///
+/// retried.notify_with(0)?; // Notified with 0, then 1, 2 and 3, then cleared.
+/// ```
+pub struct ReentrantEvent<T = ()> {
+    subscribers: RefCell<Vec<Registered<Handler<T>>>>,
+    pending_changes: RefCell<Vec<Change<T>>>,
+    pending_notifications: RefCell<VecDeque<T>>,
+    /// The number of notifications in progress.
+    depth: Cell<u32>,
+    config: EventConfig,
+}
+
+/// A subscriber of any kind, shared with the notifications in progress.
+enum Handler<T> {
+    Subscriber(Rc<dyn Subscriber<T>>),
+    Mut(Rc<RefCell<dyn SubscriberMut<T>>>),
+    Fn(Rc<dyn Fn(&T)>),
+}
+
+/// A modification of the subscribers made during a notification, applied once it completed.
+enum Change<T> {
+    Subscribe(Registered<Handler<T>>),
+    Unsubscribe(SubscriptionId),
+}
+
+/// Decrements the depth of a [ReentrantEvent] once a notification completed, even while unwinding.
+struct DepthGuard<'a>(&'a Cell<u32>);
+
+impl<T> ReentrantEvent<T> {
+    pub fn new(config: EventConfig) -> ReentrantEvent<T> {
+        ReentrantEvent {
+            subscribers: RefCell::default(),
+            pending_changes: RefCell::default(),
+            pending_notifications: RefCell::default(),
+            depth: Cell::new(0),
+            config
+        }
+    }
+
+    pub fn subscribe(&self, subscriber: Rc<dyn Subscriber<T>>) -> SubscriptionId {
+        self.register(Registered::new(Handler::Subscriber(subscriber)))
+    }
+
+    /// Subscribe a [SubscriberMut]. If it notifies this event from inside `update_mut` with
+    /// [Reentrancy::Recurse], it is skipped by the nested notification, which reports
+    /// [RustventError::AlreadyNotifying] in its [NotificationReport].
+    pub fn subscribe_mut(&self, subscriber: Rc<RefCell<dyn SubscriberMut<T>>>) -> SubscriptionId {
+        self.register(Registered::new(Handler::Mut(subscriber)))
+    }
+
+    pub fn subscribe_as_fn_with<F>(&self, func: F) -> SubscriptionId where F: Fn(&T) + 'static {
+        self.register(Registered::new(Handler::Fn(Rc::new(func))))
+    }
+
+    /// Like [subscribe_as_fn_with()](ReentrantEvent::subscribe_as_fn_with()), but controls when the closure is
+    /// notified, see [Event::subscribe_with_priority()](crate::events::Event::subscribe_with_priority()).
+    pub fn subscribe_as_fn_with_priority<F>(&self, func: F, priority: i32) -> SubscriptionId where F: Fn(&T) + 'static {
+        self.register(Registered::new(Handler::Fn(Rc::new(func))).with_priority(priority))
+    }
+
+    /// Unsubscribe from this event by the [SubscriptionId] returned when subscribing. When called during a
+    /// notification, the subscriber is removed once the notification completed.
+    ///
+    /// # Panics
+    /// Panics if no subscriber of this event was issued the id. See [try_unsubscribe()](ReentrantEvent::try_unsubscribe())
+    /// for a non-panicking alternative.
+    pub fn unsubscribe(&self, id: SubscriptionId) {
+        if let Err(err) = self.try_unsubscribe(id) {
+            panic!("{err}");
+        }
+    }
+
+    /// Like [unsubscribe()](ReentrantEvent::unsubscribe()), but returns an error instead of panicking
+    /// when no subscriber of this event was issued the id.
+    pub fn try_unsubscribe(&self, id: SubscriptionId) -> Result<(), RustventError> {
+        let subscribed = self.subscribers.borrow().iter().any(|sub| sub.id == id);
+        let mut pending_changes = self.pending_changes.borrow_mut();
+        let subscribed = pending_changes.iter().fold(subscribed, |subscribed, change| match change {
+            Change::Subscribe(sub) if sub.id == id => true,
+            Change::Unsubscribe(unsubscribed) if *unsubscribed == id => false,
+            _ => subscribed,
+        });
+
+        if !subscribed {
+            return Err(RustventError::SubscriptionNotFound(id));
+        }
+
+        if self.is_notifying() {
+            pending_changes.push(Change::Unsubscribe(id));
+        } else {
+            self.subscribers.borrow_mut().retain(|sub| sub.id != id);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the subscriber if it is still subscribed to this event, and does nothing otherwise.
+    /// Returns whether a subscriber was removed.
+    pub fn unsubscribe_if_present(&self, id: SubscriptionId) -> bool {
+        self.try_unsubscribe(id).is_ok()
+    }
+
+    /// The number of subscribers of this event, leaving out the changes deferred by a notification in progress.
+    pub fn len(&self) -> usize {
+        self.subscribers.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether a notification of this event is in progress.
+    pub fn is_notifying(&self) -> bool {
+        self.depth.get() > 0
+    }
+
+    /// Notifies subscribers, handing each of them a reference to `args`. Subscribers are notified by descending
+    /// priority, then in registration order, and changes they make to the subscribers of this event are applied
+    /// once the outermost notification completed. The returned [NotificationReport] includes the notifications
+    /// queued by subscribers, see [Reentrancy::Queue]. The subscribers are cleared as configured by the [Clear]
+    /// of this event once the queued notifications completed too, so they are notified by each of them.
+    ///
+    /// A notification made from a subscriber returns an empty report right away with [Reentrancy::Queue],
+    /// and [RustventError::MaxDepthExceeded] if too many notifications are in progress with [Reentrancy::Recurse].
+    pub fn notify_with(&self, args: T) -> Result<NotificationReport, RustventError> {
+        let depth = self.depth.get();
+
+        if depth > 0 {
+            match self.config.reentrancy {
+                Reentrancy::Queue => {
+                    self.pending_notifications.borrow_mut().push_back(args);
+                    return Ok(NotificationReport::default());
+                },
+                Reentrancy::Recurse { max_depth } if depth >= max_depth => return Err(RustventError::MaxDepthExceeded(max_depth)),
+                Reentrancy::Recurse { .. } => (),
+            }
+        }
+
+        let mut report = self.dispatch(&args);
+
+        if depth == 0 {
+            loop {
+                let Some(args) = self.pending_notifications.borrow_mut().pop_front() else { break };
+                self.apply_pending_changes();
+                report.extend(self.dispatch(&args));
+            }
+
+            let clear = self.config.clear_subscribers_after_notification;
+            self.subscribers.borrow_mut().retain(|sub| !sub.subscriber.is_cleared_by(clear));
+            self.apply_pending_changes();
+        }
+
+        Ok(report)
+    }
+
+    fn register(&self, registered: Registered<Handler<T>>) -> SubscriptionId {
+        let id = registered.id;
+
+        if self.is_notifying() {
+            self.pending_changes.borrow_mut().push(Change::Subscribe(registered));
+        } else {
+            self.subscribers.borrow_mut().push(registered);
+        }
+
+        id
+    }
+
+    /// Notifies the subscribers selected by the [Notify] of this event, without holding a borrow of the
+    /// subscribers while they are notified.
+    fn dispatch(&self, args: &T) -> NotificationReport {
+        let mut handlers: Vec<(DispatchOrder, Handler<T>)> = self.subscribers
+        .borrow()
+        .iter()
+        .filter(|sub| sub.subscriber.is_notified_by(self.config.subscribers_to_notify))
+        .map(|sub| (sub.dispatch_order(), sub.subscriber.clone()))
+        .collect();
+        handlers.sort_by_key(|(order, _)| *order);
+
+        let mut report = NotificationReport::default();
+        let catch_panics = self.config.catch_panics;
+
+        {
+            self.depth.set(self.depth.get() + 1);
+            let _depth = DepthGuard(&self.depth);
+
+            for ((_, id), handler) in handlers {
+                match handler {
+                    Handler::Subscriber(sub) => { invoke(catch_panics, id, &mut report, || sub.update_with(args)); },
+                    Handler::Mut(sub) => {
+                        let Ok(mut sub) = sub.try_borrow_mut() else {
+                            report.push_failure(id, Box::new(RustventError::AlreadyNotifying(id)));
+                            continue;
+                        };
+                        invoke(catch_panics, id, &mut report, || sub.update_mut_with(args));
+                    },
+                    Handler::Fn(func) => { invoke(catch_panics, id, &mut report, || func(args)); },
+                }
+            }
+        }
+
+        report
+    }
+
+    /// Applies the subscriptions made during the notifications that completed, in the order they were made.
+    fn apply_pending_changes(&self) {
+        let changes: Vec<Change<T>> = self.pending_changes.borrow_mut().drain(..).collect();
+        let mut subscribers = self.subscribers.borrow_mut();
+
+        for change in changes {
+            match change {
+                Change::Subscribe(registered) => subscribers.push(registered),
+                Change::Unsubscribe(id) => subscribers.retain(|sub| sub.id != id),
+            }
+        }
+    }
+}
+
+impl ReentrantEvent<()> {
+    pub fn subscribe_as_fn<F>(&self, func: F) -> SubscriptionId where F: Fn() + 'static {
+        self.subscribe_as_fn_with(move |_| func())
+    }
+
+    pub fn notify(&self) -> Result<NotificationReport, RustventError> {
+        self.notify_with(())
+    }
+}
+
+impl<T> Handler<T> {
+    fn is_notified_by(&self, notify: Notify) -> bool {
+        match self {
+            Handler::Subscriber(_) => notify.contains(Notify::SUBSCRIBERS),
+            Handler::Mut(_) => notify.contains(Notify::SUBSCRIBERS_MUT),
+            Handler::Fn(_) => notify.contains(Notify::FN_SUBSCRIBERS),
+        }
+    }
+
+    fn is_cleared_by(&self, clear: Clear) -> bool {
+        match self {
+            Handler::Subscriber(_) => clear.contains(Clear::SUBSCRIBERS),
+            Handler::Mut(_) => clear.contains(Clear::SUBSCRIBERS_MUT),
+            Handler::Fn(_) => clear.contains(Clear::FN_SUBSCRIBERS),
+        }
+    }
+}
+
+impl<T> Clone for Handler<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Handler::Subscriber(sub) => Handler::Subscriber(sub.clone()),
+            Handler::Mut(sub) => Handler::Mut(sub.clone()),
+            Handler::Fn(func) => Handler::Fn(func.clone()),
+        }
+    }
+}
+
+impl Drop for DepthGuard<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() - 1);
+    }
+}
+
+impl<T> Default for ReentrantEvent<T> {
+    fn default() -> Self {
+        Self::new(EventConfig::default())
+    }
+}
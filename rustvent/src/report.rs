@@ -0,0 +1,108 @@
+use std::{any::Any, time::Duration};
+
+use crate::{error::SubscriberError, subscription::SubscriptionId};
+
+/// Returned when an [Event](crate::events::Event) or [EventAsync](crate::event_async::EventAsync)
+/// is notified. Describes which subscribers failed, and why.
+#[derive(Debug, Default)]
+pub struct NotificationReport {
+    failures: Vec<SubscriberFailure>,
+    panics: Vec<SubscriberPanic>,
+    evicted: Vec<SubscriptionId>,
+    slow_handlers: Vec<SlowHandler>,
+    /// The number of errors left out of this report by the [ErrorPolicy](crate::events::ErrorPolicy) of the event.
+    ignored_failures: usize,
+}
+
+/// A subscriber that returned an error when it was notified.
+#[derive(Debug)]
+pub struct SubscriberFailure {
+    pub id: SubscriptionId,
+    pub error: SubscriberError,
+}
+
+/// A subscriber that panicked when it was notified.
+#[derive(Debug)]
+pub struct SubscriberPanic {
+    pub id: SubscriptionId,
+    /// The message the subscriber panicked with, if it was a string.
+    pub message: String,
+}
+
+/// A subscriber that took longer than the [slow_handler_threshold](crate::events::EventConfig::slow_handler_threshold)
+/// of the event to be notified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlowHandler {
+    pub id: SubscriptionId,
+    pub elapsed: Duration,
+}
+
+impl NotificationReport {
+    /// Whether every notified subscriber succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.failures.is_empty() && self.panics.is_empty()
+    }
+
+    /// The subscribers that failed, in the order their errors were collected.
+    pub fn failures(&self) -> &[SubscriberFailure] {
+        &self.failures
+    }
+
+    /// The subscribers that panicked.
+    pub fn panics(&self) -> &[SubscriberPanic] {
+        &self.panics
+    }
+
+    /// The subscribers that were unsubscribed because their `Mutex` was poisoned,
+    /// see [PoisonPolicy::Evict](crate::events::PoisonPolicy::Evict).
+    pub fn evicted(&self) -> &[SubscriptionId] {
+        &self.evicted
+    }
+
+    /// The subscribers that were slower than the configured threshold, see
+    /// [SlowHandlerPolicy::Report](crate::events::SlowHandlerPolicy::Report). Slow subscribers still succeeded.
+    pub fn slow_handlers(&self) -> &[SlowHandler] {
+        &self.slow_handlers
+    }
+
+    pub(crate) fn push_failure(&mut self, id: SubscriptionId, error: SubscriberError) {
+        self.failures.push(SubscriberFailure { id, error });
+    }
+
+    pub(crate) fn push_ignored_failure(&mut self) {
+        self.ignored_failures += 1;
+    }
+
+    pub(crate) fn ignored_failures(&self) -> usize {
+        self.ignored_failures
+    }
+
+    pub(crate) fn push_panic(&mut self, id: SubscriptionId, payload: Box<dyn Any + Send>) {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            String::from("Box<dyn Any>")
+        };
+
+        self.panics.push(SubscriberPanic { id, message });
+    }
+
+    /// Appends the outcome of another notification, e.g. of another event notified by the same call.
+    pub(crate) fn extend(&mut self, other: NotificationReport) {
+        self.failures.extend(other.failures);
+        self.panics.extend(other.panics);
+        self.evicted.extend(other.evicted);
+        self.slow_handlers.extend(other.slow_handlers);
+        self.ignored_failures += other.ignored_failures;
+    }
+
+    pub(crate) fn push_evicted(&mut self, id: SubscriptionId) {
+        self.evicted.push(id);
+    }
+
+    pub(crate) fn push_slow_handler(&mut self, id: SubscriptionId, elapsed: Duration) {
+        self.slow_handlers.push(SlowHandler { id, elapsed });
+    }
+}
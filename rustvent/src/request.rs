@@ -0,0 +1,220 @@
+use std::{iter, rc::Rc};
+
+use crate::{
+    subscriber::Responder,
+    subscription::{DispatchOrder, Registered, SubscriptionId},
+    error::RustventError
+};
+
+/// A closure subscribed to a [RequestEvent] through [subscribe_as_fn_with()](RequestEvent::subscribe_as_fn_with()).
+pub type FnResponder<T, R> = Box<dyn Fn(&T) -> R>;
+
+/// A **synchronous** event whose subscribers answer with a value of type `R`, e.g. plugin hooks asked
+/// whether a document can be saved. Responders are asked by descending priority, then in the order they
+/// subscribed, and their answers are either collected with [notify_collect_with()](RequestEvent::notify_collect_with())
+/// or combined by a [Reducer] with [notify_reduce_with()](RequestEvent::notify_reduce_with()).
+///
+/// Unlike an [Event](crate::events::Event), a request event keeps its subscribers after being notified.
+/// # Examples
+/// ```
+/// let mut can_save: RequestEvent<bool, Document> = RequestEvent::default();
+/// can_save.subscribe_as_fn_with(|document| !document.is_read_only());
+/// can_save.subscribe(Rc::new(SpellChecker {}));
+///
+/// if can_save.notify_reduce_with(&document, AllTrue) {
+///     document.save();
+/// }
+/// ```
+pub struct RequestEvent<R, T = ()> {
+    responders: Vec<Registered<Rc<dyn Responder<R, T>>>>,
+    fn_responders: Vec<Registered<FnResponder<T, R>>>,
+}
+
+/// Combines the answers of the responders of a [RequestEvent] into a single value. Answers are produced
+/// lazily, so a reducer that stops consuming them early leaves the remaining responders unasked.
+pub trait Reducer<R> {
+    type Output;
+
+    fn reduce(self, responses: impl Iterator<Item = R>) -> Self::Output;
+}
+
+/// `true` if every responder answered `true`, which is also the answer when nobody subscribed.
+/// Stops asking at the first `false`.
+pub struct AllTrue;
+
+/// `true` if any responder answered `true`. Stops asking at the first `true`.
+pub struct AnyTrue;
+
+/// The first `Some` answer. Stops asking once a responder answered.
+pub struct FirstSome;
+
+/// The sum of every answer.
+pub struct Sum;
+
+/// A responder of any kind, borrowed from a [RequestEvent] while it is notified.
+enum Handler<'a, R, T> {
+    Responder(&'a Rc<dyn Responder<R, T>>),
+    Fn(&'a FnResponder<T, R>),
+}
+
+impl<R, T> RequestEvent<R, T> {
+    pub fn new() -> RequestEvent<R, T> {
+        RequestEvent { responders: Vec::new(), fn_responders: Vec::new() }
+    }
+
+    /// Get all [Responders](Responder) listening to this event.
+    pub fn get_responders(&self) -> Vec<Rc<dyn Responder<R, T>>> {
+        self.responders.iter().map(|sub| sub.subscriber.clone()).collect()
+    }
+
+    /// Get all closures listening to this event.
+    pub fn get_fn_responders(&self) -> Vec<&FnResponder<T, R>> {
+        self.fn_responders.iter().map(|func| &func.subscriber).collect()
+    }
+
+    pub fn subscribe(&mut self, responder: Rc<dyn Responder<R, T>>) -> SubscriptionId {
+        self.subscribe_with_priority(responder, 0)
+    }
+
+    /// Like [subscribe()](RequestEvent::subscribe()), but controls when the responder is asked.
+    /// Responders with a higher priority are asked first.
+    pub fn subscribe_with_priority(&mut self, responder: Rc<dyn Responder<R, T>>, priority: i32) -> SubscriptionId {
+        let registered = Registered::new(responder).with_priority(priority);
+        let id = registered.id;
+        self.responders.push(registered);
+        id
+    }
+
+    /// Subscribe a closure answering with the payload of the request.
+    pub fn subscribe_as_fn_with<F>(&mut self, func: F) -> SubscriptionId where F: Fn(&T) -> R + 'static {
+        self.subscribe_as_fn_with_priority(func, 0)
+    }
+
+    /// Like [subscribe_as_fn_with()](RequestEvent::subscribe_as_fn_with()), see [subscribe_with_priority()](RequestEvent::subscribe_with_priority()).
+    pub fn subscribe_as_fn_with_priority<F>(&mut self, func: F, priority: i32) -> SubscriptionId where F: Fn(&T) -> R + 'static {
+        let box_func: FnResponder<T, R> = Box::new(func);
+        let registered = Registered::new(box_func).with_priority(priority);
+        let id = registered.id;
+        self.fn_responders.push(registered);
+        id
+    }
+
+    /// Unsubscribe from this event by the [SubscriptionId] returned when subscribing.
+    ///
+    /// # Panics
+    /// Panics if no responder of this event was issued the id. See [try_unsubscribe()](RequestEvent::try_unsubscribe())
+    /// for a non-panicking alternative.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) {
+        if let Err(err) = self.try_unsubscribe(id) {
+            panic!("{err}");
+        }
+    }
+
+    /// Like [unsubscribe()](RequestEvent::unsubscribe()), but returns an error instead of panicking
+    /// when no responder of this event was issued the id.
+    pub fn try_unsubscribe(&mut self, id: SubscriptionId) -> Result<(), RustventError> {
+        if let Some(index) = self.responders.iter().position(|sub| sub.id == id) {
+            self.responders.remove(index);
+        } else if let Some(index) = self.fn_responders.iter().position(|func| func.id == id) {
+            self.fn_responders.remove(index);
+        } else {
+            return Err(RustventError::SubscriptionNotFound(id));
+        }
+
+        Ok(())
+    }
+
+    /// Asks every responder, handing each of them a reference to `args`, and returns their answers
+    /// in the order they were asked.
+    pub fn notify_collect_with(&self, args: &T) -> Vec<R> {
+        self.responses(args).collect()
+    }
+
+    /// Asks the responders and combines their answers with `reducer`, e.g. [AllTrue] or [FirstSome].
+    /// # Examples
+    /// ```
+    /// let mut total_size: RequestEvent<u64> = RequestEvent::default();
+    /// total_size.subscribe_as_fn(|| 512);
+    /// total_size.subscribe_as_fn(|| 1024);
+    ///
+    /// assert_eq!(1536, total_size.notify_reduce(Sum));
+    /// ```
+    pub fn notify_reduce_with<A>(&self, args: &T, reducer: impl Reducer<R, Output = A>) -> A {
+        reducer.reduce(self.responses(args))
+    }
+
+    /// Asks every responder and folds their answers into `init` with `f`.
+    pub fn notify_fold_with<A>(&self, args: &T, init: A, f: impl FnMut(A, R) -> A) -> A {
+        self.responses(args).fold(init, f)
+    }
+
+    /// Lazily asks the responders for their answer, by descending priority and then in registration order.
+    fn responses<'a>(&'a self, args: &'a T) -> impl Iterator<Item = R> + 'a {
+        let mut handlers: Vec<(DispatchOrder, Handler<'_, R, T>)> = Vec::new();
+
+        handlers.extend(self.responders.iter().map(|sub| (sub.dispatch_order(), Handler::Responder(&sub.subscriber))));
+        handlers.extend(self.fn_responders.iter().map(|func| (func.dispatch_order(), Handler::Fn(&func.subscriber))));
+        handlers.sort_by_key(|(order, _)| *order);
+
+        handlers.into_iter().map(move |(_, handler)| match handler {
+            Handler::Responder(sub) => sub.respond_with(args),
+            Handler::Fn(func) => func(args),
+        })
+    }
+}
+
+impl<R> RequestEvent<R, ()> {
+    pub fn subscribe_as_fn<F>(&mut self, func: F) -> SubscriptionId where F: Fn() -> R + 'static {
+        self.subscribe_as_fn_with(move |_| func())
+    }
+
+    pub fn notify_collect(&self) -> Vec<R> {
+        self.notify_collect_with(&())
+    }
+
+    pub fn notify_reduce<A>(&self, reducer: impl Reducer<R, Output = A>) -> A {
+        self.notify_reduce_with(&(), reducer)
+    }
+
+    pub fn notify_fold<A>(&self, init: A, f: impl FnMut(A, R) -> A) -> A {
+        self.notify_fold_with(&(), init, f)
+    }
+}
+
+impl<R, T> Default for RequestEvent<R, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Reducer<bool> for AllTrue {
+    type Output = bool;
+
+    fn reduce(self, mut responses: impl Iterator<Item = bool>) -> bool {
+        responses.all(|response| response)
+    }
+}
+
+impl Reducer<bool> for AnyTrue {
+    type Output = bool;
+
+    fn reduce(self, mut responses: impl Iterator<Item = bool>) -> bool {
+        responses.any(|response| response)
+    }
+}
+
+impl<V> Reducer<Option<V>> for FirstSome {
+    type Output = Option<V>;
+
+    fn reduce(self, responses: impl Iterator<Item = Option<V>>) -> Option<V> {
+        responses.flatten().next()
+    }
+}
+
+impl<R: iter::Sum> Reducer<R> for Sum {
+    type Output = R;
+
+    fn reduce(self, responses: impl Iterator<Item = R>) -> R {
+        responses.sum()
+    }
+}
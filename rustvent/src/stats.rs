@@ -0,0 +1,221 @@
+use std::{collections::{HashMap, HashSet}, sync::{Mutex, MutexGuard, PoisonError, atomic::{AtomicU64, Ordering}}, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use crate::{report::NotificationReport, subscription::SubscriptionId};
+
+/// The upper bounds of the buckets of a [LatencyHistogram]. Invocations slower than the last bound are
+/// counted in an extra bucket.
+pub const LATENCY_BUCKETS: [Duration; 6] = [
+    Duration::from_micros(10),
+    Duration::from_micros(100),
+    Duration::from_millis(1),
+    Duration::from_millis(10),
+    Duration::from_millis(100),
+    Duration::from_secs(1),
+];
+
+/// A snapshot of the statistics of an [Event](crate::events::Event), [EventAsync](crate::event_async::EventAsync)
+/// or [EventFuture](crate::event_future::EventFuture), returned by their `stats` method.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventStats {
+    /// The number of times the event was notified, whether or not it had subscribers.
+    pub notifications: u64,
+    /// The number of notifications that reached at least one [Subscriber](crate::subscriber::Subscriber)
+    /// or [TrySubscriber](crate::subscriber::TrySubscriber).
+    pub subscribers_notified: u64,
+    /// The number of notifications that reached at least one mutable subscriber.
+    pub subscribers_mut_notified: u64,
+    /// The number of notifications that reached at least one closure.
+    pub fn_subscribers_notified: u64,
+    /// The number of times a subscriber of any kind was invoked.
+    pub invocations: u64,
+    /// The number of subscribers that failed or panicked, including the errors left out of the [NotificationReport]
+    /// of each notification by its [ErrorPolicy](crate::events::ErrorPolicy).
+    pub failures: u64,
+    /// The number of weak subscribers pruned after their subscriber was dropped.
+    pub dead_subscribers_pruned: u64,
+    /// When the event was last notified, if it was notified since it was created or its statistics were reset.
+    pub last_notified: Option<SystemTime>,
+}
+
+/// How long the invocations of a single subscriber took, returned by the `latency` and `latencies`
+/// methods of an [Event](crate::events::Event) or [EventAsync](crate::event_async::EventAsync).
+/// # Examples
+/// ```
+/// let mut saved: Event = Event::new(EventConfig { clear_subscribers_after_notification: Clear::None, ..Default::default() });
+/// saved.subscribe(Rc::new(Indexer {}));
+/// saved.notify();
+///
+/// for (id, latency) in saved.latencies() {
+///     println!("{id:?}: {} invocations, {:?} at most", latency.count(), latency.max());
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    buckets: [u64; LATENCY_BUCKETS.len() + 1],
+    total: Duration,
+    max: Duration,
+}
+
+impl LatencyHistogram {
+    /// The number of invocations.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    /// The time spent in every invocation together.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// The average duration of an invocation, or zero if there were none.
+    pub fn mean(&self) -> Duration {
+        match self.count() {
+            0 => Duration::ZERO,
+            count => self.total.div_f64(count as f64),
+        }
+    }
+
+    /// The duration of the slowest invocation.
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// The number of invocations per bucket, paired with the upper bound of the bucket from
+    /// [LATENCY_BUCKETS]. The last bucket has no upper bound.
+    pub fn buckets(&self) -> impl Iterator<Item = (Option<Duration>, u64)> + '_ {
+        LATENCY_BUCKETS
+        .iter()
+        .map(|bound| Some(*bound))
+        .chain([None])
+        .zip(self.buckets.iter().copied())
+    }
+
+    pub(crate) fn record(&mut self, elapsed: Duration) {
+        let bucket = LATENCY_BUCKETS
+        .iter()
+        .position(|bound| elapsed <= *bound)
+        .unwrap_or(LATENCY_BUCKETS.len());
+
+        self.buckets[bucket] += 1;
+        self.total += elapsed;
+        self.max = self.max.max(elapsed);
+    }
+}
+
+/// The statistics of an event, updated through `&self` so they can be shared with other threads.
+#[derive(Debug, Default)]
+pub(crate) struct StatsCounter {
+    notifications: AtomicU64,
+    subscribers_notified: AtomicU64,
+    subscribers_mut_notified: AtomicU64,
+    fn_subscribers_notified: AtomicU64,
+    invocations: AtomicU64,
+    failures: AtomicU64,
+    dead_subscribers_pruned: AtomicU64,
+    /// Nanoseconds since the Unix epoch, or `0` if the event was not notified.
+    last_notified: AtomicU64,
+    latencies: Mutex<HashMap<SubscriptionId, LatencyHistogram>>,
+}
+
+impl StatsCounter {
+    pub(crate) fn snapshot(&self) -> EventStats {
+        let last_notified = match self.last_notified.load(Ordering::Relaxed) {
+            0 => None,
+            nanos => Some(UNIX_EPOCH + Duration::from_nanos(nanos)),
+        };
+
+        EventStats {
+            notifications: self.notifications.load(Ordering::Relaxed),
+            subscribers_notified: self.subscribers_notified.load(Ordering::Relaxed),
+            subscribers_mut_notified: self.subscribers_mut_notified.load(Ordering::Relaxed),
+            fn_subscribers_notified: self.fn_subscribers_notified.load(Ordering::Relaxed),
+            invocations: self.invocations.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            dead_subscribers_pruned: self.dead_subscribers_pruned.load(Ordering::Relaxed),
+            last_notified,
+        }
+    }
+
+    pub(crate) fn reset(&self) {
+        for counter in [
+            &self.notifications,
+            &self.subscribers_notified,
+            &self.subscribers_mut_notified,
+            &self.fn_subscribers_notified,
+            &self.invocations,
+            &self.failures,
+            &self.dead_subscribers_pruned,
+            &self.last_notified,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+
+        self.latencies().clear();
+    }
+
+    pub(crate) fn latency(&self, id: SubscriptionId) -> Option<LatencyHistogram> {
+        self.latencies().get(&id).cloned()
+    }
+
+    /// Every histogram, by descending time spent in the subscriber.
+    pub(crate) fn latencies_snapshot(&self) -> Vec<(SubscriptionId, LatencyHistogram)> {
+        let mut latencies: Vec<(SubscriptionId, LatencyHistogram)> = self.latencies()
+        .iter()
+        .map(|(id, latency)| (*id, latency.clone()))
+        .collect();
+
+        latencies.sort_by_key(|(_, latency)| std::cmp::Reverse(latency.total));
+        latencies
+    }
+
+    pub(crate) fn record_latency(&self, id: SubscriptionId, elapsed: Duration) {
+        self.latencies().entry(id).or_default().record(elapsed);
+    }
+
+    /// Drops the histograms of unsubscribed subscribers once there are more histograms than `len`
+    /// subscribers, so an event whose subscribers come and go does not keep a histogram for each of them.
+    pub(crate) fn prune_latencies(&self, len: usize, subscribed: impl Iterator<Item = SubscriptionId>) {
+        let mut latencies = self.latencies();
+
+        if latencies.len() > len {
+            let subscribed: HashSet<SubscriptionId> = subscribed.collect();
+            latencies.retain(|id, _| subscribed.contains(id));
+        }
+    }
+
+    fn latencies(&self) -> MutexGuard<'_, HashMap<SubscriptionId, LatencyHistogram>> {
+        self.latencies.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Records a notification that produced `report`.
+    pub(crate) fn record_notification(&self, report: &NotificationReport) {
+        let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(1, |since_epoch| since_epoch.as_nanos().max(1) as u64);
+
+        self.notifications.fetch_add(1, Ordering::Relaxed);
+        let failures = report.failures().len() + report.panics().len() + report.ignored_failures();
+        self.failures.fetch_add(failures as u64, Ordering::Relaxed);
+        self.last_notified.store(now, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_invocations(&self, invocations: usize) {
+        self.invocations.fetch_add(invocations as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_subscribers_notified(&self) {
+        self.subscribers_notified.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_subscribers_mut_notified(&self) {
+        self.subscribers_mut_notified.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_fn_subscribers_notified(&self) {
+        self.fn_subscribers_notified.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_pruned(&self, pruned: usize) {
+        self.dead_subscribers_pruned.fetch_add(pruned as u64, Ordering::Relaxed);
+    }
+}
@@ -1,23 +1,115 @@
 use std::sync::Arc;
 
-pub trait Subscriber {
-   fn update(&self);
+use crate::{error::SubscriberError, event_future::BoxFuture};
+
+/// Implemented by types that want to be notified by an [Event](crate::events::Event).
+///
+/// Subscribers of an `Event<()>` implement [update()](Subscriber::update()), subscribers of
+/// an `Event<T>` implement [update_with()](Subscriber::update_with()) to receive the payload.
+/// Both methods have a default body so that either one can be implemented alone: a subscriber
+/// implementing neither compiles, but does nothing when notified.
+pub trait Subscriber<T = ()> {
+   fn update(&self) {}
+
+   fn update_with(&self, _args: &T) {
+      self.update();
+   }
 }
 
-pub trait SubscriberMut {
-   fn update_mut(&mut self);
+/// Implemented by types that need to mutate themselves when notified by an [Event](crate::events::Event).
+///
+/// Implement [update_mut()](SubscriberMut::update_mut()) or [update_mut_with()](SubscriberMut::update_mut_with());
+/// like a [Subscriber], a subscriber implementing neither does nothing when notified.
+pub trait SubscriberMut<T = ()> {
+   fn update_mut(&mut self) {}
+
+   fn update_mut_with(&mut self, _args: &T) {
+      self.update_mut();
+   }
 }
 
-pub trait SubscriberAsync {
-   fn update(&self);
+/// Implemented by types that want to be notified by an [EventAsync](crate::event_async::EventAsync).
+///
+/// Implement [update()](SubscriberAsync::update()) or [update_with()](SubscriberAsync::update_with());
+/// a subscriber implementing neither does nothing when notified.
+pub trait SubscriberAsync<T = ()> {
+   fn update(&self) {}
+
+   fn update_with(&self, _args: &T) {
+      self.update();
+   }
+
+   fn into_arc(self) -> Arc<dyn SubscriberAsync<T> + Send + Sync + 'static>
+   where
+   Self: SubscriberAsync<T> + Sized + Send + Sync + 'static {
+      Arc::new(self)
+   }
+}
+
+/// Implemented by types that need to mutate themselves when notified by an [EventAsync](crate::event_async::EventAsync).
+///
+/// Implement [update_mut()](SubscriberAsyncMut::update_mut()) or [update_mut_with()](SubscriberAsyncMut::update_mut_with());
+/// a subscriber implementing neither does nothing when notified.
+pub trait SubscriberAsyncMut<T = ()> {
+   fn update_mut(&mut self) {}
+
+   fn update_mut_with(&mut self, _args: &T) {
+      self.update_mut();
+   }
+}
+
+/// Implemented by subscribers of an [Event](crate::events::Event) that can fail. Their errors are
+/// handled according to the [ErrorPolicy](crate::events::ErrorPolicy) of the event and returned in
+/// its [NotificationReport](crate::report::NotificationReport).
+///
+/// Implement [try_update()](TrySubscriber::try_update()) or [try_update_with()](TrySubscriber::try_update_with());
+/// a subscriber implementing neither always succeeds without doing anything.
+pub trait TrySubscriber<T = ()> {
+   fn try_update(&self) -> Result<(), SubscriberError> {
+      Ok(())
+   }
+
+   fn try_update_with(&self, _args: &T) -> Result<(), SubscriberError> {
+      self.try_update()
+   }
+}
+
+/// Implemented by subscribers of an [EventAsync](crate::event_async::EventAsync) that can fail.
+///
+/// Implement [try_update()](TrySubscriberAsync::try_update()) or [try_update_with()](TrySubscriberAsync::try_update_with());
+/// a subscriber implementing neither always succeeds without doing anything.
+pub trait TrySubscriberAsync<T = ()> {
+   fn try_update(&self) -> Result<(), SubscriberError> {
+      Ok(())
+   }
+
+   fn try_update_with(&self, _args: &T) -> Result<(), SubscriberError> {
+      self.try_update()
+   }
+}
+
+/// Implemented by types that want to be notified by an [EventFuture](crate::event_future::EventFuture).
+/// The returned future is awaited by the event instead of blocking a thread.
+///
+/// Implement [update()](AsyncSubscriber::update()) or [update_with()](AsyncSubscriber::update_with());
+/// a subscriber implementing neither returns a future that completes right away.
+pub trait AsyncSubscriber<T = ()> {
+   fn update(&self) -> BoxFuture<'_, ()> {
+      Box::pin(async {})
+   }
+
+   fn update_with<'a>(&'a self, _args: &'a T) -> BoxFuture<'a, ()> {
+      self.update()
+   }
 
-   fn into_arc(self) -> Arc<(dyn SubscriberAsync + Send + Sync + 'static)> 
-   where 
-   Self: SubscriberAsync + Sized + Send + Sync + 'static {
+   fn into_arc(self) -> Arc<dyn AsyncSubscriber<T> + Send + Sync + 'static>
+   where
+   Self: AsyncSubscriber<T> + Sized + Send + Sync + 'static {
       Arc::new(self)
    }
 }
 
-pub trait SubscriberAsyncMut {
-   fn update_mut(&mut self);
+/// Implemented by types that answer a [RequestEvent](crate::request::RequestEvent) with a value of type `R`.
+pub trait Responder<R, T = ()> {
+   fn respond_with(&self, args: &T) -> R;
 }
@@ -0,0 +1,132 @@
+use std::{cmp::Reverse, collections::HashSet, sync::{Arc, atomic::{AtomicBool, AtomicU64, Ordering}}};
+
+static NEXT_SUBSCRIPTION_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Returned by every `subscribe*` method of [Event](crate::events::Event) and
+/// [EventAsync](crate::event_async::EventAsync). Hand it back to `unsubscribe` to remove
+/// the subscriber, whether it is a trait object or a closure.
+///
+/// Ids are unique for the lifetime of the process, so an id issued by one event never
+/// matches a subscription of another event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SubscriptionId(u64);
+
+impl SubscriptionId {
+    pub(crate) fn next() -> SubscriptionId {
+        SubscriptionId(NEXT_SUBSCRIPTION_ID.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Returned by the `subscribe*_scoped` methods of [Event](crate::events::Event) and
+/// [EventAsync](crate::event_async::EventAsync). The subscription lasts as long as the guard:
+/// once it is dropped the subscriber is no longer notified, and the event releases it the
+/// next time it is notified or a subscriber is unsubscribed from it. Subscribing does not
+/// release it.
+///
+/// The guard is `Send`, so it may be moved to another thread than the one owning the event.
+#[must_use = "dropping the guard immediately unsubscribes the subscriber"]
+#[derive(Debug)]
+pub struct SubscriptionGuard {
+    id: SubscriptionId,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl SubscriptionGuard {
+    /// The id of the guarded subscription.
+    pub fn id(&self) -> SubscriptionId {
+        self.id
+    }
+}
+
+impl Drop for SubscriptionGuard {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+}
+
+/// Sorts subscribers in the order they are notified, see [Registered::dispatch_order()].
+pub(crate) type DispatchOrder = (Reverse<i32>, SubscriptionId);
+
+/// A subscriber stored by an event together with the [SubscriptionId] it was issued.
+/// `P` is the type of the predicate filtering its notifications, for the kinds of subscribers
+/// that may be subscribed with one.
+pub(crate) struct Registered<S, P = ()> {
+    pub(crate) id: SubscriptionId,
+    pub(crate) subscriber: S,
+    /// Subscribers with a higher priority are notified first, see `subscribe_with_priority`.
+    pub(crate) priority: i32,
+    /// How many more notifications the subscriber receives before it is unsubscribed, if limited.
+    remaining: Option<u32>,
+    filter: Option<P>,
+    cancelled: Option<Arc<AtomicBool>>,
+}
+
+impl<S, P> Registered<S, P> {
+    pub(crate) fn new(subscriber: S) -> Registered<S, P> {
+        Registered { id: SubscriptionId::next(), subscriber, priority: 0, remaining: None, filter: None, cancelled: None }
+    }
+
+    pub(crate) fn with_priority(self, priority: i32) -> Registered<S, P> {
+        Registered { priority, ..self }
+    }
+
+    /// Limits the subscription to `times` notifications, see `subscribe_n`.
+    ///
+    /// # Panics
+    /// Panics if `times` is zero.
+    pub(crate) fn with_limit(self, times: u32) -> Registered<S, P> {
+        assert!(times > 0, "A subscriber must be notified at least once.");
+        Registered { remaining: Some(times), ..self }
+    }
+
+    /// Only notifies the subscriber when `filter` accepts the payload, see `subscribe_filtered`.
+    pub(crate) fn with_filter(self, filter: P) -> Registered<S, P> {
+        Registered { filter: Some(filter), ..self }
+    }
+
+    /// Whether the subscriber should be notified with `args`, i.e. it has no filter or its filter accepts them.
+    pub(crate) fn accepts<T>(&self, args: &T) -> bool where P: Fn(&T) -> bool {
+        self.filter.as_ref().is_none_or(|filter| filter(args))
+    }
+
+    /// The key subscribers are notified by: highest priority first, then in registration order,
+    /// since ids are issued in increasing order.
+    pub(crate) fn dispatch_order(&self) -> DispatchOrder {
+        (Reverse(self.priority), self.id)
+    }
+
+    /// Registers `subscriber` for as long as the returned [SubscriptionGuard] is alive.
+    pub(crate) fn scoped(subscriber: S) -> (Registered<S, P>, SubscriptionGuard) {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let registered = Registered { 
+            id: SubscriptionId::next(), 
+            subscriber, 
+            priority: 0,
+            remaining: None,
+            filter: None,
+            cancelled: Some(cancelled.clone()) 
+        };
+        let guard = SubscriptionGuard { id: registered.id, cancelled };
+
+        (registered, guard)
+    }
+
+    /// Whether this subscription should still be notified, i.e. its guard, if any, has not been dropped.
+    pub(crate) fn is_active(&self) -> bool {
+        self.cancelled
+        .as_ref()
+        .is_none_or(|cancelled| !cancelled.load(Ordering::Acquire))
+    }
+}
+
+/// Counts a notification against every limited subscription whose id is in `notified`, and
+/// unsubscribes those that have received all their notifications.
+pub(crate) fn remove_exhausted<S, P>(subscribers: &mut Vec<Registered<S, P>>, notified: &HashSet<SubscriptionId>) {
+    subscribers.retain_mut(|sub| match sub.remaining.as_mut() {
+        Some(remaining) if notified.contains(&sub.id) => {
+            *remaining -= 1;
+            *remaining > 0
+        },
+        _ => true,
+    });
+}
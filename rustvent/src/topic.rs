@@ -0,0 +1,209 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use crate::{
+    subscriber::{Subscriber, SubscriberMut},
+    subscription::SubscriptionId,
+    error::RustventError,
+    events::{Event, EventConfig, Clear},
+    report::NotificationReport
+};
+
+/// The payload handed to the subscribers of a [TopicRouter]: the concrete topic it was published on,
+/// which may differ from the pattern the subscriber registered, and the published value.
+#[derive(Debug)]
+pub struct TopicMessage<T = ()> {
+    pub topic: String,
+    pub payload: T,
+}
+
+/// Routes values published on hierarchical, dot separated topics such as `orders.eu.created` to the
+/// subscribers of every matching pattern. In a pattern, `*` matches exactly one segment of the topic and
+/// `#` matches zero or more segments, so `orders.*` matches `orders.created` but not `orders.eu.created`,
+/// which `orders.#` matches. Any other segment matches itself.
+///
+/// Patterns are kept in a trie, so publishing visits the segments of the topic rather than every pattern.
+/// Each pattern is backed by an [Event], and a subscriber whose pattern matches a topic several ways is
+/// still notified once per publication.
+/// # Examples
+/// ```
+/// let mut router: TopicRouter<f64> = TopicRouter::new();
+/// router.subscribe_as_fn("telemetry.*.cpu", |message| println!("{}: {}", message.topic, message.payload));
+///
+/// router.publish("telemetry.eu-1.cpu", 0.42);
+/// ```
+pub struct TopicRouter<T = ()> {
+    root: Node,
+    events: HashMap<String, PatternEvent<T>>,
+    patterns: HashMap<SubscriptionId, String>,
+}
+
+/// The [Event] backing a pattern of a [TopicRouter], with the number of subscriptions to the pattern so that
+/// it is removed once the last one is.
+struct PatternEvent<T> {
+    event: Event<TopicMessage<T>>,
+    subscriptions: usize,
+}
+
+/// A segment of the patterns of a [TopicRouter], keyed by the next segment, wildcards included.
+#[derive(Default)]
+struct Node {
+    children: HashMap<String, Node>,
+    /// The pattern ending at this node, if any is subscribed to.
+    pattern: Option<String>,
+}
+
+const SEPARATOR: char = '.';
+const SINGLE_WILDCARD: &str = "*";
+const MULTI_WILDCARD: &str = "#";
+
+impl<T> TopicRouter<T> {
+    pub fn new() -> TopicRouter<T> {
+        TopicRouter { root: Node::default(), events: HashMap::new(), patterns: HashMap::new() }
+    }
+
+    /// Subscribe a [Subscriber] to every topic matching `pattern`.
+    pub fn subscribe(&mut self, pattern: &str, subscriber: Rc<dyn Subscriber<TopicMessage<T>>>) -> SubscriptionId {
+        let id = self.event_mut(pattern).subscribe(subscriber);
+        self.patterns.insert(id, pattern.to_string());
+        id
+    }
+
+    /// Subscribe a [SubscriberMut] to every topic matching `pattern`.
+    pub fn subscribe_mut(&mut self, pattern: &str, subscriber: Rc<RefCell<dyn SubscriberMut<TopicMessage<T>>>>) -> SubscriptionId {
+        let id = self.event_mut(pattern).subscribe_mut(subscriber);
+        self.patterns.insert(id, pattern.to_string());
+        id
+    }
+
+    /// Subscribe a closure to every topic matching `pattern`.
+    pub fn subscribe_as_fn<F>(&mut self, pattern: &str, func: F) -> SubscriptionId where F: Fn(&TopicMessage<T>) + 'static {
+        let id = self.event_mut(pattern).subscribe_as_fn_with(func);
+        self.patterns.insert(id, pattern.to_string());
+        id
+    }
+
+    /// Unsubscribe from this router by the [SubscriptionId] returned when subscribing.
+    ///
+    /// # Panics
+    /// Panics if no subscriber of this router was issued the id. See [try_unsubscribe()](TopicRouter::try_unsubscribe())
+    /// for a non-panicking alternative.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) {
+        if let Err(err) = self.try_unsubscribe(id) {
+            panic!("{err}");
+        }
+    }
+
+    /// Like [unsubscribe()](TopicRouter::unsubscribe()), but returns an error instead of panicking
+    /// when no subscriber of this router was issued the id. Patterns left without subscribers are
+    /// removed from the trie.
+    pub fn try_unsubscribe(&mut self, id: SubscriptionId) -> Result<(), RustventError> {
+        let pattern = self.patterns.remove(&id).ok_or(RustventError::SubscriptionNotFound(id))?;
+
+        if let Some(pattern_event) = self.events.get_mut(&pattern) {
+            pattern_event.event.unsubscribe_if_present(id);
+            pattern_event.subscriptions -= 1;
+
+            if pattern_event.subscriptions == 0 {
+                self.events.remove(&pattern);
+                self.root.remove(&mut pattern.split(SEPARATOR));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The patterns subscribed to that match `topic`, in no particular order.
+    pub fn matching_patterns(&self, topic: &str) -> Vec<&str> {
+        let segments: Vec<&str> = topic.split(SEPARATOR).collect();
+        let mut patterns = Vec::new();
+
+        self.root.collect(&segments, &mut patterns);
+        patterns.sort_unstable();
+        patterns.dedup();
+        patterns
+    }
+
+    /// Notifies the subscribers of every pattern matching `topic` with a [TopicMessage] carrying `payload`.
+    /// The returned [NotificationReport] combines the reports of every matching pattern.
+    pub fn publish(&mut self, topic: &str, payload: T) -> NotificationReport {
+        let patterns: Vec<String> = self.matching_patterns(topic).into_iter().map(String::from).collect();
+        let message = TopicMessage { topic: topic.to_string(), payload };
+        let mut report = NotificationReport::default();
+
+        for pattern in patterns {
+            if let Some(pattern_event) = self.events.get_mut(&pattern) {
+                report.extend(pattern_event.event.notify_with(&message));
+            }
+        }
+
+        report
+    }
+
+    /// The event backing `pattern`, counting the subscription about to be made to it.
+    fn event_mut(&mut self, pattern: &str) -> &mut Event<TopicMessage<T>> {
+        let pattern_event = self.events.entry(pattern.to_string()).or_insert_with(|| {
+            self.root.insert(pattern);
+            PatternEvent {
+                event: Event::new(EventConfig { clear_subscribers_after_notification: Clear::None, ..Default::default() }),
+                subscriptions: 0,
+            }
+        });
+
+        pattern_event.subscriptions += 1;
+        &mut pattern_event.event
+    }
+}
+
+impl Node {
+    fn insert(&mut self, pattern: &str) {
+        let node = pattern
+        .split(SEPARATOR)
+        .fold(self, |node, segment| node.children.entry(segment.to_string()).or_default());
+
+        node.pattern = Some(pattern.to_string());
+    }
+
+    /// Removes the pattern made of `segments`. Returns whether this node is no longer needed.
+    fn remove<'a>(&mut self, segments: &mut impl Iterator<Item = &'a str>) -> bool {
+        match segments.next() {
+            Some(segment) => {
+                if self.children.get_mut(segment).is_some_and(|child| child.remove(segments)) {
+                    self.children.remove(segment);
+                }
+            },
+            None => self.pattern = None,
+        }
+
+        self.pattern.is_none() && self.children.is_empty()
+    }
+
+    /// Adds the patterns below this node matching the remaining `segments` of a topic to `patterns`.
+    fn collect<'a>(&'a self, segments: &[&str], patterns: &mut Vec<&'a str>) {
+        if let Some(node) = self.children.get(MULTI_WILDCARD) {
+            for skipped in 0..=segments.len() {
+                node.collect(&segments[skipped..], patterns);
+            }
+        }
+
+        let Some((segment, rest)) = segments.split_first() else {
+            patterns.extend(self.pattern.as_deref());
+            return;
+        };
+
+        if let Some(node) = self.children.get(*segment) {
+            node.collect(rest, patterns);
+        }
+
+        if *segment != SINGLE_WILDCARD {
+            if let Some(node) = self.children.get(SINGLE_WILDCARD) {
+                node.collect(rest, patterns);
+            }
+        }
+    }
+}
+
+impl<T> Default for TopicRouter<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,55 @@
+//! Instrumentation of [Event](crate::events::Event) and [EventAsync](crate::event_async::EventAsync),
+//! emitted through `tracing` when the `tracing` feature is enabled. Without it the macros below expand
+//! to nothing and their arguments are never evaluated.
+
+use crate::report::NotificationReport;
+
+/// Emits a `tracing` event at `$level`, e.g. `trace_event!(debug, subscriber = ?id, "subscribed")`.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    };
+}
+
+/// Creates a `tracing` span at `$level`, or a [Span](crate::trace::Span) that does nothing.
+macro_rules! trace_span {
+    ($level:ident, $($arg:tt)+) => {{
+        #[cfg(feature = "tracing")]
+        let span = tracing::span!(tracing::Level::$level, $($arg)+);
+        #[cfg(not(feature = "tracing"))]
+        let span = $crate::trace::Span;
+        span
+    }};
+}
+
+/// Stands in for a `tracing` span when the `tracing` feature is disabled.
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct Span;
+
+#[cfg(not(feature = "tracing"))]
+impl Span {
+    pub(crate) fn entered(self) -> Span {
+        self
+    }
+}
+
+/// Emits an event for each subscriber listed in `report`.
+#[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+pub(crate) fn report(report: &NotificationReport) {
+    #[cfg(feature = "tracing")]
+    {
+        for failure in report.failures() {
+            tracing::warn!(subscriber = ?failure.id, error = %failure.error, "subscriber failed");
+        }
+        for panic in report.panics() {
+            tracing::error!(subscriber = ?panic.id, panic = %panic.message, "subscriber panicked");
+        }
+        for id in report.evicted() {
+            tracing::warn!(subscriber = ?id, "subscriber evicted");
+        }
+        for slow in report.slow_handlers() {
+            tracing::warn!(subscriber = ?slow.id, elapsed = ?slow.elapsed, "subscriber was slow");
+        }
+    }
+}
@@ -0,0 +1,515 @@
+/// Checks that [Event] and [EventAsync] honor the [EventConfig] contract the same way.
+/// Every test is written once against [Harness] and run for both implementations.
+#[cfg(test)]
+mod conformance_tests {
+    use std::{cell::RefCell, rc::Rc, sync::{Arc, Mutex, atomic::{AtomicU32, Ordering}}, time::Duration};
+
+    use rustvent::{error::RustventError, event_async::EventAsync, report::NotificationReport, stats::{EventStats, LatencyHistogram}, subscription::SubscriptionId};
+    use rustvent::events::{Clear, Event, EventConfig, Notify};
+    use rustvent::subscriber::{Subscriber, SubscriberAsync, SubscriberAsyncMut, SubscriberMut};
+
+    /// Sums the payloads it is notified with.
+    #[derive(Default, Clone)]
+    struct Counter(Arc<AtomicU32>);
+
+    impl Counter {
+        fn get(&self) -> u32 {
+            self.0.load(Ordering::SeqCst)
+        }
+
+        fn add(&self, args: &u32) {
+            self.0.fetch_add(*args, Ordering::SeqCst);
+        }
+    }
+
+    impl Subscriber<u32> for Counter {
+        fn update_with(&self, args: &u32) {
+            self.add(args);
+        }
+    }
+
+    impl SubscriberMut<u32> for Counter {
+        fn update_mut_with(&mut self, args: &u32) {
+            self.add(args);
+        }
+    }
+
+    impl SubscriberAsync<u32> for Counter {
+        fn update_with(&self, args: &u32) {
+            self.add(args);
+        }
+    }
+
+    impl SubscriberAsyncMut<u32> for Counter {
+        fn update_mut_with(&mut self, args: &u32) {
+            self.add(args);
+        }
+    }
+
+    /// The operations shared by both events, with the subscribers they hand out kept by the harness.
+    trait Harness {
+        fn with_config(config: EventConfig) -> Self;
+        fn subscribe(&mut self, counter: &Counter) -> SubscriptionId;
+        fn subscribe_mut(&mut self, counter: &Counter) -> SubscriptionId;
+        fn subscribe_fn(&mut self, counter: &Counter) -> SubscriptionId;
+        fn subscribe_once(&mut self, counter: &Counter) -> SubscriptionId;
+        fn subscribe_filtered(&mut self, counter: &Counter, predicate: fn(&u32) -> bool) -> SubscriptionId;
+        fn stats(&self) -> EventStats;
+        fn reset_stats(&self);
+        fn latency(&self, id: SubscriptionId) -> Option<LatencyHistogram>;
+        /// Subscribes a weak pointer to `counter`, kept alive until [drop_weak_subscribers()](Harness::drop_weak_subscribers()).
+        fn subscribe_weak(&mut self, counter: &Counter) -> SubscriptionId;
+        fn drop_weak_subscribers(&mut self);
+        fn subscribe_fn_n(&mut self, counter: &Counter, times: u32) -> SubscriptionId;
+        /// Unsubscribes the `index`th subscriber added through [subscribe_mut()](Harness::subscribe_mut()).
+        fn try_unsubscribe_mut(&mut self, index: usize) -> Result<(), RustventError>;
+        fn try_unsubscribe(&mut self, id: SubscriptionId) -> Result<(), RustventError>;
+        fn notify(&mut self, args: u32) -> NotificationReport;
+        fn notify_selected(&mut self, args: u32, selection: Notify) -> NotificationReport;
+        fn clear(&mut self, selection: Clear);
+        /// The number of subscribers, mutable subscribers and closures.
+        fn counts(&self) -> (usize, usize, usize);
+    }
+
+    struct SyncHarness {
+        event: Event<u32>,
+        subscribers_mut: Vec<Rc<RefCell<Counter>>>,
+        weak_subscribers: Vec<Rc<Counter>>,
+    }
+
+    struct AsyncHarness {
+        event: EventAsync<u32>,
+        subscribers_mut: Vec<Arc<Mutex<Counter>>>,
+        weak_subscribers: Vec<Arc<Counter>>,
+    }
+
+    impl Harness for SyncHarness {
+        fn with_config(config: EventConfig) -> Self {
+            SyncHarness { event: Event::new(config), subscribers_mut: Vec::new(), weak_subscribers: Vec::new() }
+        }
+
+        fn subscribe(&mut self, counter: &Counter) -> SubscriptionId {
+            self.event.subscribe(Rc::new(counter.clone()))
+        }
+
+        fn subscribe_mut(&mut self, counter: &Counter) -> SubscriptionId {
+            let sub = Rc::new(RefCell::new(counter.clone()));
+            self.subscribers_mut.push(sub.clone());
+            self.event.subscribe_mut(sub)
+        }
+
+        fn subscribe_fn(&mut self, counter: &Counter) -> SubscriptionId {
+            let counter = counter.clone();
+            self.event.subscribe_as_fn_with(move |args| counter.add(args))
+        }
+
+        fn subscribe_once(&mut self, counter: &Counter) -> SubscriptionId {
+            self.event.subscribe_once(Rc::new(counter.clone()))
+        }
+
+        fn subscribe_filtered(&mut self, counter: &Counter, predicate: fn(&u32) -> bool) -> SubscriptionId {
+            self.event.subscribe_filtered(Rc::new(counter.clone()), predicate)
+        }
+
+        fn stats(&self) -> EventStats {
+            self.event.stats()
+        }
+
+        fn reset_stats(&self) {
+            self.event.reset_stats();
+        }
+
+        fn latency(&self, id: SubscriptionId) -> Option<LatencyHistogram> {
+            self.event.latency(id)
+        }
+
+        fn subscribe_weak(&mut self, counter: &Counter) -> SubscriptionId {
+            let sub = Rc::new(counter.clone());
+            self.weak_subscribers.push(sub.clone());
+            self.event.subscribe_weak(Rc::downgrade(&sub) as std::rc::Weak<dyn Subscriber<u32>>)
+        }
+
+        fn drop_weak_subscribers(&mut self) {
+            self.weak_subscribers.clear();
+        }
+
+        fn subscribe_fn_n(&mut self, counter: &Counter, times: u32) -> SubscriptionId {
+            let counter = counter.clone();
+            self.event.subscribe_as_fn_n(move |args| counter.add(args), times)
+        }
+
+        fn try_unsubscribe_mut(&mut self, index: usize) -> Result<(), RustventError> {
+            self.event.try_unsubscribe_mut(self.subscribers_mut[index].clone())
+        }
+
+        fn try_unsubscribe(&mut self, id: SubscriptionId) -> Result<(), RustventError> {
+            self.event.try_unsubscribe(id)
+        }
+
+        fn notify(&mut self, args: u32) -> NotificationReport {
+            self.event.notify_with(&args)
+        }
+
+        fn notify_selected(&mut self, args: u32, selection: Notify) -> NotificationReport {
+            self.event.notify_selected_with(&args, selection)
+        }
+
+        fn clear(&mut self, selection: Clear) {
+            self.event.clear(selection)
+        }
+
+        fn counts(&self) -> (usize, usize, usize) {
+            (self.event.get_subscribers().len(), self.event.get_subscribers_mut().len(), self.event.get_fn_subscribers().len())
+        }
+    }
+
+    impl Harness for AsyncHarness {
+        fn with_config(config: EventConfig) -> Self {
+            AsyncHarness { event: EventAsync::new(config), subscribers_mut: Vec::new(), weak_subscribers: Vec::new() }
+        }
+
+        fn subscribe(&mut self, counter: &Counter) -> SubscriptionId {
+            self.event.subscribe(Arc::new(counter.clone()))
+        }
+
+        fn subscribe_mut(&mut self, counter: &Counter) -> SubscriptionId {
+            let sub = Arc::new(Mutex::new(counter.clone()));
+            self.subscribers_mut.push(sub.clone());
+            self.event.subscribe_mut(sub)
+        }
+
+        fn subscribe_fn(&mut self, counter: &Counter) -> SubscriptionId {
+            let counter = counter.clone();
+            self.event.subscribe_as_fn_with(move |args| counter.add(args))
+        }
+
+        fn subscribe_once(&mut self, counter: &Counter) -> SubscriptionId {
+            self.event.subscribe_once(Arc::new(counter.clone()))
+        }
+
+        fn subscribe_filtered(&mut self, counter: &Counter, predicate: fn(&u32) -> bool) -> SubscriptionId {
+            self.event.subscribe_filtered(Arc::new(counter.clone()), predicate)
+        }
+
+        fn stats(&self) -> EventStats {
+            self.event.stats()
+        }
+
+        fn reset_stats(&self) {
+            self.event.reset_stats();
+        }
+
+        fn latency(&self, id: SubscriptionId) -> Option<LatencyHistogram> {
+            self.event.latency(id)
+        }
+
+        fn subscribe_weak(&mut self, counter: &Counter) -> SubscriptionId {
+            let sub = Arc::new(counter.clone());
+            self.weak_subscribers.push(sub.clone());
+            self.event.subscribe_weak(Arc::downgrade(&sub) as std::sync::Weak<dyn SubscriberAsync<u32> + Send + Sync>)
+        }
+
+        fn drop_weak_subscribers(&mut self) {
+            self.weak_subscribers.clear();
+        }
+
+        fn subscribe_fn_n(&mut self, counter: &Counter, times: u32) -> SubscriptionId {
+            let counter = counter.clone();
+            self.event.subscribe_as_fn_n(move |args| counter.add(args), times)
+        }
+
+        fn try_unsubscribe_mut(&mut self, index: usize) -> Result<(), RustventError> {
+            self.event.try_unsubscribe_mut(self.subscribers_mut[index].clone())
+        }
+
+        fn try_unsubscribe(&mut self, id: SubscriptionId) -> Result<(), RustventError> {
+            self.event.try_unsubscribe(id)
+        }
+
+        fn notify(&mut self, args: u32) -> NotificationReport {
+            self.event.notify_with(&args)
+        }
+
+        fn notify_selected(&mut self, args: u32, selection: Notify) -> NotificationReport {
+            self.event.notify_selected_with(&args, selection)
+        }
+
+        fn clear(&mut self, selection: Clear) {
+            self.event.clear(selection)
+        }
+
+        fn counts(&self) -> (usize, usize, usize) {
+            (self.event.get_subscribers().len(), self.event.get_subscribers_mut().len(), self.event.get_fn_subscribers().len())
+        }
+    }
+
+    /// Subscribes one subscriber of every kind and notifies them once with `1`.
+    /// Returns the counters of the subscriber, mutable subscriber and closure.
+    fn notify_every_kind<H: Harness>(event: &mut H) -> (Counter, Counter, Counter) {
+        let counters = (Counter::default(), Counter::default(), Counter::default());
+        event.subscribe(&counters.0);
+        event.subscribe_mut(&counters.1);
+        event.subscribe_fn(&counters.2);
+
+        assert!(event.notify(1).is_ok());
+        counters
+    }
+
+    fn notifying_with(subscribers_to_notify: Notify) -> EventConfig {
+        EventConfig { subscribers_to_notify, clear_subscribers_after_notification: Clear::None, ..Default::default() }
+    }
+
+    fn clearing(clear_subscribers_after_notification: Clear) -> EventConfig {
+        EventConfig { clear_subscribers_after_notification, ..Default::default() }
+    }
+
+    fn notify_all_reaches_every_kind_of_subscriber<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        let (sub, sub_mut, func) = notify_every_kind(&mut event);
+
+        assert_eq!((1, 1, 1), (sub.get(), sub_mut.get(), func.get()));
+    }
+
+    fn notify_only_reaches_selected_kind_of_subscriber<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::OnlySubscribers));
+        let (sub, sub_mut, func) = notify_every_kind(&mut event);
+        assert_eq!((1, 0, 0), (sub.get(), sub_mut.get(), func.get()));
+
+        let mut event = H::with_config(notifying_with(Notify::OnlySubscribersMut));
+        let (sub, sub_mut, func) = notify_every_kind(&mut event);
+        assert_eq!((0, 1, 0), (sub.get(), sub_mut.get(), func.get()));
+
+        let mut event = H::with_config(notifying_with(Notify::OnlyFnSubscribers));
+        let (sub, sub_mut, func) = notify_every_kind(&mut event);
+        assert_eq!((0, 0, 1), (sub.get(), sub_mut.get(), func.get()));
+    }
+
+    fn clear_removes_selected_kind_of_subscriber<H: Harness>() {
+        let mut event = H::with_config(clearing(Clear::OnlySubscribers));
+        notify_every_kind(&mut event);
+        assert_eq!((0, 1, 1), event.counts());
+
+        let mut event = H::with_config(clearing(Clear::OnlySubscribersMut));
+        notify_every_kind(&mut event);
+        assert_eq!((1, 0, 1), event.counts());
+
+        let mut event = H::with_config(clearing(Clear::OnlyFuncSubscribers));
+        notify_every_kind(&mut event);
+        assert_eq!((1, 1, 0), event.counts());
+
+        let mut event = H::with_config(clearing(Clear::None));
+        notify_every_kind(&mut event);
+        assert_eq!((1, 1, 1), event.counts());
+    }
+
+    fn combined_selections_notify_and_clear_several_kinds<H: Harness>() {
+        let mut event = H::with_config(EventConfig {
+            subscribers_to_notify: Notify::SUBSCRIBERS | Notify::SUBSCRIBERS_MUT,
+            clear_subscribers_after_notification: Clear::FN_SUBSCRIBERS | Clear::SUBSCRIBERS_MUT,
+            ..Default::default()
+        });
+        let (sub, sub_mut, func) = notify_every_kind(&mut event);
+
+        assert_eq!((1, 1, 0), (sub.get(), sub_mut.get(), func.get()));
+        assert_eq!((1, 0, 0), event.counts());
+    }
+
+    fn clear_all_removes_every_kind_of_subscriber<H: Harness>() {
+        let mut event = H::with_config(clearing(Clear::All));
+        notify_every_kind(&mut event);
+        assert_eq!((0, 0, 0), event.counts());
+
+        let mut event = H::with_config(clearing(Clear::None));
+        notify_every_kind(&mut event);
+        event.clear(Clear::ALL - Clear::SUBSCRIBERS);
+        assert_eq!((1, 0, 0), event.counts());
+    }
+
+    fn notify_selected_overrides_configured_selection<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::OnlySubscribers));
+        let (sub, sub_mut, func) = notify_every_kind(&mut event);
+
+        assert!(event.notify_selected(10, Notify::ALL - Notify::SUBSCRIBERS).is_ok());
+        assert_eq!((1, 10, 10), (sub.get(), sub_mut.get(), func.get()));
+
+        event.notify_selected(100, Notify::NONE);
+        assert_eq!((1, 10, 10), (sub.get(), sub_mut.get(), func.get()));
+    }
+
+    fn unsubscribe_mut_only_removes_mutable_subscriber<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        event.subscribe(&Counter::default());
+        event.subscribe_mut(&Counter::default());
+        event.subscribe_mut(&Counter::default());
+
+        assert_eq!(Ok(()), event.try_unsubscribe_mut(0));
+        assert_eq!((1, 1, 0), event.counts());
+        assert_eq!(Err(RustventError::SubscriberNotFound), event.try_unsubscribe_mut(0));
+    }
+
+    fn unsubscribe_by_id_removes_every_kind_of_subscriber<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        let ids = [
+            event.subscribe(&Counter::default()),
+            event.subscribe_mut(&Counter::default()),
+            event.subscribe_fn(&Counter::default()),
+        ];
+
+        for id in ids {
+            assert_eq!(Ok(()), event.try_unsubscribe(id));
+        }
+
+        assert_eq!((0, 0, 0), event.counts());
+        assert_eq!(Err(RustventError::SubscriptionNotFound(ids[0])), event.try_unsubscribe(ids[0]));
+    }
+
+    fn unsubscribed_subscribers_are_not_notified<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        let counter = Counter::default();
+        let id = event.subscribe_mut(&counter);
+
+        event.notify(2);
+        event.try_unsubscribe(id).unwrap();
+        event.notify(3);
+
+        assert_eq!(2, counter.get());
+    }
+
+    fn limited_subscribers_are_unsubscribed_after_their_notifications<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        let (once, twice, always) = (Counter::default(), Counter::default(), Counter::default());
+        let once_id = event.subscribe_once(&once);
+        event.subscribe_fn_n(&twice, 2);
+        event.subscribe(&always);
+
+        for _ in 0..3 {
+            event.notify(1);
+        }
+
+        assert_eq!((1, 2, 3), (once.get(), twice.get(), always.get()));
+        assert_eq!((1, 0, 0), event.counts());
+        assert_eq!(Err(RustventError::SubscriptionNotFound(once_id)), event.try_unsubscribe(once_id));
+    }
+
+    fn weak_subscribers_are_pruned_once_dropped<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        let counter = Counter::default();
+        event.subscribe_weak(&counter);
+
+        event.notify(1);
+        assert_eq!((1, 0, 0), event.counts());
+
+        event.drop_weak_subscribers();
+        assert_eq!((0, 0, 0), event.counts());
+        event.notify(1);
+
+        assert_eq!(1, counter.get());
+        assert_eq!(1, event.stats().dead_subscribers_pruned);
+    }
+
+    fn filtered_subscribers_are_only_notified_with_accepted_payloads<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        let (even, odd) = (Counter::default(), Counter::default());
+        event.subscribe_filtered(&even, |args| args % 2 == 0);
+        event.subscribe_filtered(&odd, |args| args % 2 == 1);
+
+        for args in [2, 4, 6] {
+            event.notify(args);
+        }
+
+        assert_eq!((12, 0), (even.get(), odd.get()));
+        assert_eq!(3, event.stats().subscribers_notified);
+
+        let mut event = H::with_config(notifying_with(Notify::All));
+        event.subscribe_filtered(&odd, |args| args % 2 == 1);
+        event.notify(2);
+
+        assert_eq!(0, event.stats().subscribers_notified);
+    }
+
+    fn stats_count_notifications_and_invocations<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        assert_eq!(EventStats::default(), event.stats());
+
+        event.notify(1);
+        let (subscriber, subscriber_mut, func) = (Counter::default(), Counter::default(), Counter::default());
+        event.subscribe(&subscriber);
+        event.subscribe_mut(&subscriber_mut);
+        event.subscribe_fn(&func);
+        event.notify(1);
+
+        let stats = event.stats();
+        assert_eq!(2, stats.notifications);
+        assert_eq!((1, 1, 1), (stats.subscribers_notified, stats.subscribers_mut_notified, stats.fn_subscribers_notified));
+        assert_eq!(3, stats.invocations);
+        assert_eq!(0, stats.failures);
+        assert!(stats.last_notified.is_some());
+
+        event.reset_stats();
+        assert_eq!(EventStats::default(), event.stats());
+    }
+
+    fn subscribers_slower_than_threshold_are_reported<H: Harness>() {
+        let mut event = H::with_config(EventConfig {
+            slow_handler_threshold: Some(Duration::ZERO),
+            record_latency: true,
+            ..notifying_with(Notify::All)
+        });
+        let counter = Counter::default();
+        let id = event.subscribe_fn(&counter);
+
+        let report = event.notify(1);
+        event.notify(1);
+
+        assert_eq!(vec![id], report.slow_handlers().iter().map(|slow| slow.id).collect::<Vec<_>>());
+        assert!(report.is_ok());
+        assert_eq!(Some(2), event.latency(id).map(|latency| latency.count()));
+
+        event.reset_stats();
+        assert_eq!(None, event.latency(id));
+    }
+
+    fn latency_is_only_recorded_when_configured<H: Harness>() {
+        let mut event = H::with_config(notifying_with(Notify::All));
+        let counter = Counter::default();
+        let id = event.subscribe_fn(&counter);
+
+        event.notify(1);
+
+        assert_eq!(1, counter.get());
+        assert_eq!(None, event.latency(id));
+    }
+
+    macro_rules! conformance_tests {
+        ($($name:ident),* $(,)?) => {
+            mod event {
+                use super::*;
+                $( #[test] fn $name() { super::$name::<SyncHarness>(); } )*
+            }
+
+            mod event_async {
+                use super::*;
+                $( #[test] fn $name() { super::$name::<AsyncHarness>(); } )*
+            }
+        };
+    }
+
+    conformance_tests!(
+        notify_all_reaches_every_kind_of_subscriber,
+        notify_only_reaches_selected_kind_of_subscriber,
+        clear_removes_selected_kind_of_subscriber,
+        combined_selections_notify_and_clear_several_kinds,
+        clear_all_removes_every_kind_of_subscriber,
+        notify_selected_overrides_configured_selection,
+        unsubscribe_mut_only_removes_mutable_subscriber,
+        unsubscribe_by_id_removes_every_kind_of_subscriber,
+        unsubscribed_subscribers_are_not_notified,
+        limited_subscribers_are_unsubscribed_after_their_notifications,
+        weak_subscribers_are_pruned_once_dropped,
+        filtered_subscribers_are_only_notified_with_accepted_payloads,
+        stats_count_notifications_and_invocations,
+        subscribers_slower_than_threshold_are_reported,
+        latency_is_only_recorded_when_configured,
+    );
+
+}
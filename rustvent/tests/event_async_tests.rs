@@ -1,8 +1,10 @@
 #[cfg(test)]
 mod event_async_tests {
-    use std::sync::{Arc, Mutex};
+    use std::{sync::{Arc, Mutex}, thread, time::Duration};
 
-    use rustvent::{event_async::EventAsync, subscriber::{SubscriberAsync, SubscriberAsyncMut}};
+    use rustvent::{event_async::EventAsync, error::SubscriberError, subscriber::{SubscriberAsync, SubscriberAsyncMut, TrySubscriberAsync}};
+    use rustvent::events::{Clear, ErrorPolicy, EventConfig, PoisonPolicy, Dispatch, SlowHandlerPolicy};
+    use rustvent::pool::ThreadPool;
 
     struct ProcessBusinessLogic {
         process_completed: EventAsync
@@ -31,7 +33,7 @@ mod event_async_tests {
         logic.process_completed.subscribe(some_sub);        
         logic.on_process_completed();  
 
-        assert_eq!(1, logic.process_completed.times_subscribers_notified); 
+        assert_eq!(1, logic.process_completed.stats().subscribers_notified); 
     }
 
     #[test]
@@ -60,7 +62,7 @@ mod event_async_tests {
         logic.process_completed.subscribe(second_sub);        
         logic.on_process_completed();  
 
-        assert_eq!(1, logic.process_completed.times_subscribers_notified); 
+        assert_eq!(1, logic.process_completed.stats().subscribers_notified); 
     }
 
     #[test]
@@ -86,7 +88,7 @@ mod event_async_tests {
         logic.process_completed.subscribe(some_sub);               
         logic.on_process_completed();  
 
-        assert_eq!(1, logic.process_completed.times_subscribers_notified); 
+        assert_eq!(1, logic.process_completed.stats().subscribers_notified); 
     }
 
     #[test]
@@ -131,7 +133,274 @@ mod event_async_tests {
         logic.process_completed.subscribe_as_fn(|| println!("Second closure notified..."));        
         logic.process_completed.notify_fn_subscribers();  
 
-        assert_eq!(1, logic.process_completed.times_func_subscribers_notified); 
+        assert_eq!(1, logic.process_completed.stats().fn_subscribers_notified); 
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn event_async_typed_subscribers_receive_payload() {
+        struct OrderSubscriber {
+            received: Mutex<Vec<u32>>
+        }
+        impl SubscriberAsync<u32> for OrderSubscriber {
+            fn update_with(&self, order_id: &u32) {
+                self.received.lock().unwrap().push(*order_id);
+            }
+        }
+
+        struct OrderTotal {
+            total: u32
+        }
+        impl SubscriberAsyncMut<u32> for OrderTotal {
+            fn update_mut_with(&mut self, order_id: &u32) {
+                self.total += order_id;
+            }
+        }
+
+        let mut order_changed: EventAsync<u32> = EventAsync::default();
+        let some_sub = Arc::new(OrderSubscriber { received: Mutex::new(Vec::new()) });
+        let total_sub = Arc::new(Mutex::new(OrderTotal { total: 0 }));
+        let fn_received = Arc::new(Mutex::new(Vec::new()));
+        let fn_received_clone = fn_received.clone();
+
+        order_changed.subscribe(some_sub.clone());
+        order_changed.subscribe_mut(total_sub.clone());
+        order_changed.subscribe_as_fn_with(move |order_id| fn_received_clone.lock().unwrap().push(*order_id));
+        order_changed.notify_with(&7);
+
+        assert_eq!(vec![7], *some_sub.received.lock().unwrap());
+        assert_eq!(vec![7], *fn_received.lock().unwrap());
+        assert_eq!(1, order_changed.stats().subscribers_notified);
+        assert_eq!(1, order_changed.stats().fn_subscribers_notified);
+    }
+
+    #[test]
+    fn event_async_try_subscribers_errors_are_reported() {
+        struct Validator {}
+        impl TrySubscriberAsync<u32> for Validator {
+            fn try_update_with(&self, quantity: &u32) -> Result<(), SubscriberError> {
+                if *quantity == 0 {
+                    return Err("quantity must be positive".into());
+                }
+                Ok(())
+            }
+        }
+
+        let mut order_placed: EventAsync<u32> = EventAsync::default();
+        let id = order_placed.subscribe_try(Arc::new(Validator {}));
+
+        let report = order_placed.notify_with(&0);
+
+        assert_eq!(1, report.failures().len());
+        assert_eq!(id, report.failures()[0].id);
+        assert_eq!("quantity must be positive", report.failures()[0].error.to_string());
+    }
+
+    struct PanickingSubscriber {}
+    impl SubscriberAsync for PanickingSubscriber {
+        fn update(&self) {
+            panic!("PanickingSubscriber failed");
+        }
+    }
+
+    struct CountingSubscriber {
+        count: u8,
+        panic_on_next_update: bool
+    }
+    impl SubscriberAsyncMut for CountingSubscriber {
+        fn update_mut(&mut self) {
+            if self.panic_on_next_update {
+                self.panic_on_next_update = false;
+                panic!("CountingSubscriber failed");
+            }
+            self.count += 1;
+        }
+    }
+
+    fn poisoned_subscriber() -> Arc<Mutex<CountingSubscriber>> {
+        let sub = Arc::new(Mutex::new(CountingSubscriber { count: 0, panic_on_next_update: true }));
+        let mut event = EventAsync::default();
+        event.subscribe_mut(sub.clone());
+        event.notify_subscribers_mut();
+
+        assert!(sub.is_poisoned());
+        sub
+    }
+
+    #[test]
+    fn event_async_panicking_subscriber_does_not_prevent_others_from_being_notified() {
+        let mut event = EventAsync::default();
+        let notified = Arc::new(Mutex::new(0));
+        let notified_clone = notified.clone();
+
+        let id = event.subscribe(PanickingSubscriber {}.into_arc());
+        event.subscribe_as_fn(move || *notified_clone.lock().unwrap() += 1);
+        let report = event.notify();
+
+        assert!(!report.is_ok());
+        assert_eq!(id, report.panics()[0].id);
+        assert_eq!("PanickingSubscriber failed", report.panics()[0].message);
+        assert_eq!(1, *notified.lock().unwrap());
+    }
+
+    #[test]
+    fn event_async_stop_at_first_error_still_reports_panics() {
+        struct Validator {}
+        impl TrySubscriberAsync for Validator {
+            fn try_update(&self) -> Result<(), SubscriberError> {
+                Err("order is empty".into())
+            }
+        }
+
+        struct PanickingValidator {}
+        impl TrySubscriberAsync for PanickingValidator {
+            fn try_update(&self) -> Result<(), SubscriberError> {
+                panic!("PanickingValidator failed");
+            }
+        }
+
+        let mut event = EventAsync::new(EventConfig {
+            on_subscriber_error: ErrorPolicy::StopAtFirst,
+            dispatch: Dispatch::Sequential,
+            ..Default::default()
+        });
+        let failing = event.subscribe_try(Arc::new(Validator {}));
+        let panicking = event.subscribe_try(Arc::new(PanickingValidator {}));
+
+        let report = event.notify();
+
+        assert_eq!(failing, report.failures()[0].id);
+        assert_eq!(panicking, report.panics()[0].id);
+        assert_eq!(2, event.stats().failures);
+    }
+
+    #[test]
+    fn event_async_poisoned_subscriber_mut_is_recovered() {
+        let sub = poisoned_subscriber();
+        let mut event = EventAsync::new(EventConfig { on_poisoned_subscriber: PoisonPolicy::Recover, ..Default::default() });
+
+        event.subscribe_mut(sub.clone());
+        let report = event.notify_subscribers_mut();
+
+        assert!(report.is_ok());
+        assert!(!sub.is_poisoned());
+        assert_eq!(1, sub.lock().unwrap().count);
+    }
+
+    #[test]
+    fn event_async_poisoned_subscriber_mut_is_evicted() {
+        let sub = poisoned_subscriber();
+        let mut event = EventAsync::new(EventConfig { on_poisoned_subscriber: PoisonPolicy::Evict, ..Default::default() });
+
+        let id = event.subscribe_mut(sub.clone());
+        let report = event.notify_subscribers_mut();
+
+        assert_eq!(&[id], report.evicted());
+        assert!(event.try_unsubscribe(id).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Mutex is poisoned")]
+    fn event_async_poisoned_subscriber_mut_panics_when_propagated() {
+        let sub = poisoned_subscriber();
+        let mut event = EventAsync::new(EventConfig { on_poisoned_subscriber: PoisonPolicy::Propagate, ..Default::default() });
+
+        event.subscribe_mut(sub.clone());
+        event.notify_subscribers_mut();
+    }
+
+    #[test]
+    fn event_async_subscribers_are_notified_on_shared_thread_pool() {
+        let pool = Arc::new(ThreadPool::new(2));
+        let mut first_event: EventAsync<u32> = EventAsync::new(EventConfig { dispatch: Dispatch::Pool(pool.clone()), ..Default::default() });
+        let mut second_event: EventAsync<u32> = EventAsync::new(EventConfig { dispatch: Dispatch::Pool(pool.clone()), ..Default::default() });
+        let total = Arc::new(Mutex::new(0));
+
+        for _ in 0..8 {
+            let total = total.clone();
+            first_event.subscribe_as_fn_with(move |amount| *total.lock().unwrap() += amount);
+        }
+        let total_clone = total.clone();
+        second_event.subscribe_as_fn_with(move |amount| *total_clone.lock().unwrap() += amount);
+
+        first_event.notify_with(&1);
+        second_event.notify_with(&10);
+
+        assert_eq!(18, *total.lock().unwrap());
+        assert_eq!(2, pool.workers());
+    }
+
+    #[test]
+    fn event_async_panicking_subscriber_on_thread_pool_does_not_prevent_others_from_being_notified() {
+        let pool = Arc::new(ThreadPool::new(1));
+        let mut event = EventAsync::new(EventConfig { dispatch: Dispatch::Pool(pool), ..Default::default() });
+        let notified = Arc::new(Mutex::new(0));
+        let notified_clone = notified.clone();
+
+        let id = event.subscribe(PanickingSubscriber {}.into_arc());
+        event.subscribe_as_fn(move || *notified_clone.lock().unwrap() += 1);
+        let report = event.notify();
+        event.subscribe_as_fn(|| ());
+        event.notify();
+
+        assert_eq!(id, report.panics()[0].id);
+        assert_eq!(1, *notified.lock().unwrap());
+    }
+
+    #[test]
+    fn event_async_sequential_dispatch_notifies_subscribers_in_order_on_calling_thread() {
+        let mut event = EventAsync::new(EventConfig { dispatch: Dispatch::Sequential, ..Default::default() });
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        for i in 0..3 {
+            let calls = calls.clone();
+            event.subscribe_as_fn(move || calls.lock().unwrap().push((i, std::thread::current().id())));
+        }
+        event.notify();
+
+        let current = std::thread::current().id();
+        assert_eq!(vec![(0, current), (1, current), (2, current)], *calls.lock().unwrap());
+    }
+
+    #[test]
+    fn event_async_unsubscribing_keeps_registration_order() {
+        let mut event = EventAsync::new(EventConfig { dispatch: Dispatch::Sequential, ..Default::default() });
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let ids: Vec<_> = (0..4).map(|i| {
+            let calls = calls.clone();
+            event.subscribe_as_fn(move || calls.lock().unwrap().push(i))
+        }).collect();
+        event.unsubscribe(ids[0]);
+        event.notify();
+
+        assert_eq!(vec![1, 2, 3], *calls.lock().unwrap());
+    }
+
+    #[test]
+    fn event_async_slow_subscriber_is_handed_to_callback() {
+        let slow = Arc::new(Mutex::new(Vec::new()));
+        let slow_clone = slow.clone();
+        let mut document_saved: EventAsync = EventAsync::new(EventConfig {
+            clear_subscribers_after_notification: Clear::None,
+            slow_handler_threshold: Some(Duration::from_millis(50)),
+            record_latency: true,
+            on_slow_handler: SlowHandlerPolicy::Callback(Arc::new(move |id, elapsed| slow_clone.lock().unwrap().push((id, elapsed)))),
+            ..Default::default()
+        });
+
+        for _ in 0..10 {
+            document_saved.subscribe_as_fn(|| ());
+        }
+        let indexer = document_saved.subscribe_as_fn(|| thread::sleep(Duration::from_millis(60)));
+
+        let report = document_saved.notify();
+
+        let slow = slow.lock().unwrap();
+        assert_eq!(vec![indexer], slow.iter().map(|(id, _)| *id).collect::<Vec<_>>());
+        assert!(slow[0].1 >= Duration::from_millis(60));
+        assert!(report.slow_handlers().is_empty());
+        assert_eq!(indexer, document_saved.latencies()[0].0);
+        assert_eq!(11, document_saved.latencies().len());
+    }
+
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod event_bus_tests {
+    use std::{cell::{Cell, RefCell}, rc::Rc, sync::{Arc, Mutex, atomic::{AtomicU32, Ordering}}, thread};
+
+    use rustvent::{bus::{EventBus, EventBusAsync}, error::RustventError};
+    use rustvent::subscriber::{Subscriber, SubscriberAsync};
+
+    struct OrderPlaced {
+        order_id: u32,
+    }
+
+    struct OrderCancelled {
+        order_id: u32,
+    }
+
+    struct Orders {
+        placed: RefCell<Vec<u32>>,
+    }
+
+    impl Subscriber<OrderPlaced> for Orders {
+        fn update_with(&self, event: &OrderPlaced) {
+            self.placed.borrow_mut().push(event.order_id);
+        }
+    }
+
+    #[derive(Default)]
+    struct Total(AtomicU32);
+
+    impl SubscriberAsync<OrderPlaced> for Total {
+        fn update_with(&self, event: &OrderPlaced) {
+            self.0.fetch_add(event.order_id, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn event_bus_routes_events_by_type() {
+        let mut bus = EventBus::new();
+        let orders = Rc::new(Orders { placed: RefCell::new(Vec::new()) });
+        let cancelled = Rc::new(Cell::new(0));
+        let cancelled_clone = cancelled.clone();
+
+        bus.subscribe::<OrderPlaced>(orders.clone());
+        bus.subscribe_as_fn(move |event: &OrderCancelled| cancelled_clone.set(event.order_id));
+
+        bus.publish(OrderPlaced { order_id: 1 });
+        bus.publish(OrderPlaced { order_id: 2 });
+        bus.publish(OrderCancelled { order_id: 3 });
+
+        assert_eq!(vec![1, 2], *orders.placed.borrow());
+        assert_eq!(3, cancelled.get());
+        assert_eq!(2, bus.event::<OrderPlaced>().unwrap().stats().subscribers_notified);
+    }
+
+    #[test]
+    fn event_bus_publishing_without_subscribers_does_nothing() {
+        let mut bus = EventBus::default();
+
+        let report = bus.publish(OrderPlaced { order_id: 1 });
+
+        assert!(report.is_ok());
+        assert!(bus.event::<OrderPlaced>().is_none());
+    }
+
+    #[test]
+    fn event_bus_subscriber_can_unsubscribe_by_id() {
+        let mut bus = EventBus::new();
+        let calls = Rc::new(Cell::new(0));
+        let calls_clone = calls.clone();
+
+        bus.subscribe_as_fn(|_: &OrderCancelled| ());
+        let id = bus.subscribe_as_fn(move |_: &OrderPlaced| calls_clone.set(calls_clone.get() + 1));
+        bus.unsubscribe(id);
+        bus.publish(OrderPlaced { order_id: 1 });
+
+        assert_eq!(0, calls.get());
+        assert_eq!(Err(RustventError::SubscriptionNotFound(id)), bus.try_unsubscribe(id));
+    }
+
+    #[test]
+    fn event_bus_async_can_be_shared_between_threads() {
+        let bus = Arc::new(EventBusAsync::new());
+        let total = Arc::new(Total::default());
+        let cancelled = Arc::new(Mutex::new(Vec::new()));
+        let cancelled_clone = cancelled.clone();
+
+        bus.subscribe::<OrderPlaced>(total.clone());
+        bus.subscribe_as_fn(move |event: &OrderCancelled| cancelled_clone.lock().unwrap().push(event.order_id));
+
+        let publishers: Vec<_> = (1..=4)
+        .map(|order_id| {
+            let bus = bus.clone();
+            thread::spawn(move || {
+                bus.publish(OrderPlaced { order_id });
+                bus.publish(OrderCancelled { order_id });
+            })
+        })
+        .collect();
+
+        for publisher in publishers {
+            publisher.join().unwrap();
+        }
+
+        assert_eq!(10, total.0.load(Ordering::SeqCst));
+        assert_eq!(4, cancelled.lock().unwrap().len());
+    }
+
+    #[test]
+    fn event_bus_async_subscriber_can_unsubscribe_by_id() {
+        let bus = EventBusAsync::default();
+        let total = Arc::new(Total::default());
+
+        let id = bus.subscribe::<OrderPlaced>(total.clone());
+        bus.unsubscribe(id);
+        let report = bus.publish(OrderPlaced { order_id: 1 });
+
+        assert!(report.is_ok());
+        assert_eq!(0, total.0.load(Ordering::SeqCst));
+        assert_eq!(Err(RustventError::SubscriptionNotFound(id)), bus.try_unsubscribe(id));
+    }
+}
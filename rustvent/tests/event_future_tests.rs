@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod event_future_tests {
+    use std::{future::Future, pin::Pin, sync::{Arc, Mutex, atomic::{AtomicBool, Ordering}}, task::{Context, Poll}};
+
+    use rustvent::{event_future::{block_on, BoxFuture, EventFuture}, subscriber::AsyncSubscriber};
+    use rustvent::events::{Await, Clear, EventConfig};
+
+    /// Returns `Pending` once, handing control back to the other futures of the notification.
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    struct Recorder {
+        calls: Arc<Mutex<Vec<u32>>>,
+    }
+
+    impl AsyncSubscriber<u32> for Recorder {
+        fn update_with<'a>(&'a self, args: &'a u32) -> BoxFuture<'a, ()> {
+            Box::pin(async move {
+                YieldNow(false).await;
+                self.calls.lock().unwrap().push(*args);
+            })
+        }
+    }
+
+    struct PanickingSubscriber {}
+
+    impl AsyncSubscriber for PanickingSubscriber {
+        fn update(&self) -> BoxFuture<'_, ()> {
+            Box::pin(async { panic!("PanickingSubscriber failed") })
+        }
+    }
+
+    #[test]
+    fn event_future_subscriber_receives_payload() {
+        let mut event: EventFuture<u32> = EventFuture::default();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        event.subscribe(Recorder { calls: calls.clone() }.into_arc());
+        let report = block_on(event.notify_with(&7));
+
+        assert!(report.is_ok());
+        assert_eq!(vec![7], *calls.lock().unwrap());
+        assert_eq!(1, event.stats().subscribers_notified);
+    }
+
+    #[test]
+    fn event_future_subscribers_are_awaited_concurrently() {
+        let mut event = EventFuture::default();
+        let flag = Arc::new(AtomicBool::new(false));
+        let waiting_flag = flag.clone();
+
+        // Would never complete if the second closure only started once the first one completed.
+        event.subscribe_as_fn(move || {
+            let flag = waiting_flag.clone();
+            async move {
+                while !flag.load(Ordering::SeqCst) {
+                    YieldNow(false).await;
+                }
+            }
+        });
+        event.subscribe_as_fn(move || {
+            let flag = flag.clone();
+            async move { flag.store(true, Ordering::SeqCst) }
+        });
+
+        assert!(block_on(event.notify()).is_ok());
+    }
+
+    #[test]
+    fn event_future_subscribers_are_awaited_sequentially_in_order() {
+        let mut event: EventFuture<u32> = EventFuture::new(EventConfig { await_subscribers: Await::Sequentially, ..Default::default() });
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        for i in 0..3 {
+            let calls = calls.clone();
+            event.subscribe_as_fn_with(move |args| {
+                let (calls, args) = (calls.clone(), *args);
+                async move {
+                    YieldNow(false).await;
+                    calls.lock().unwrap().push(args + i);
+                }
+            });
+        }
+        block_on(event.notify_with(&10));
+
+        assert_eq!(vec![10, 11, 12], *calls.lock().unwrap());
+    }
+
+    #[test]
+    fn event_future_panicking_subscriber_does_not_prevent_others_from_completing() {
+        let mut event = EventFuture::default();
+        let notified = Arc::new(AtomicBool::new(false));
+        let notified_clone = notified.clone();
+
+        let id = event.subscribe(PanickingSubscriber {}.into_arc());
+        event.subscribe_as_fn(move || {
+            let notified = notified_clone.clone();
+            async move { notified.store(true, Ordering::SeqCst) }
+        });
+        let report = block_on(event.notify());
+
+        assert_eq!(id, report.panics()[0].id);
+        assert_eq!("PanickingSubscriber failed", report.panics()[0].message);
+        assert!(notified.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn event_future_subscribers_can_unsubscribe() {
+        let mut event = EventFuture::new(EventConfig { clear_subscribers_after_notification: Clear::None, ..Default::default() });
+
+        let sub = PanickingSubscriber {}.into_arc();
+        event.subscribe(sub.clone());
+        let id = event.subscribe_as_fn(|| async {});
+
+        event.unsubscribe(sub);
+        event.unsubscribe(id);
+
+        assert!(event.get_subscribers().is_empty());
+        assert!(event.get_fn_subscribers().is_empty());
+        assert!(!event.unsubscribe_if_present(id));
+    }
+
+    #[test]
+    fn event_future_stats_only_count_kinds_with_subscribers() {
+        let mut event = EventFuture::new(EventConfig { clear_subscribers_after_notification: Clear::None, ..Default::default() });
+
+        block_on(event.notify());
+        event.subscribe_as_fn(|| async {});
+        block_on(event.notify());
+
+        let stats = event.stats();
+        assert_eq!(2, stats.notifications);
+        assert_eq!(0, stats.subscribers_notified);
+        assert_eq!(1, stats.fn_subscribers_notified);
+        assert_eq!(1, stats.invocations);
+    }
+
+    #[test]
+    fn event_future_clears_subscribers_after_notification() {
+        let mut event = EventFuture::default();
+
+        event.subscribe_as_fn(|| async {});
+        block_on(event.notify());
+
+        assert!(event.get_fn_subscribers().is_empty());
+    }
+
+    #[test]
+    fn event_future_notification_can_be_spawned_on_multi_threaded_runtimes() {
+        fn assert_send<F: Future + Send>(future: F) -> F { future }
+
+        let mut event: EventFuture<u32> = EventFuture::default();
+        event.subscribe_as_fn_with(|_| async {});
+
+        block_on(assert_send(event.notify_with(&1)));
+    }
+
+}
@@ -11,13 +11,6 @@ mod event_tests {
         process_error: Event
     }
 
-    #[derive(Event, Default)]
-    struct ProcessLogic {
-        id: u8,
-        process_completed: Event,
-        process_error: Event
-    }
-
     #[test]
     fn type_can_subscribe_to_event() {
         struct BusinessSubscriber {}
@@ -35,7 +28,7 @@ mod event_tests {
         logic.process_completed.subscribe(rc_sub.clone());
         logic.on_process_completed();
 
-        assert_eq!(1, logic.process_completed.times_subscribers_notified);
+        assert_eq!(1, logic.process_completed.stats().subscribers_notified);
     }
 
     #[test]
@@ -66,7 +59,7 @@ mod event_tests {
         logic.process_completed.subscribe(rc_sub.clone());
         logic.notify_subscribers();
 
-        assert_eq!(1, logic.process_completed.times_subscribers_notified);
+        assert_eq!(1, logic.process_completed.stats().subscribers_notified);
     }
 
     #[test]
@@ -87,14 +80,15 @@ mod event_tests {
         logic.on_process_completed();
         logic.on_process_completed();
 
-        assert_eq!(1, logic.process_completed.times_subscribers_notified);
+        assert_eq!(1, logic.process_completed.stats().subscribers_notified);
     }
 
     #[test]
     fn closure_can_subscribe_to_an_event() {
         let config = EventConfig { 
             subscribers_to_notify: Notify::OnlyFnSubscribers, 
-            clear_subscribers_after_notification: Clear::All 
+            clear_subscribers_after_notification: Clear::All,
+            ..Default::default()
         };
 
         let mut logic = ProcessBusinessLogic {
@@ -105,14 +99,15 @@ mod event_tests {
         logic.process_completed.subscribe_as_fn(|| println!("Closure: run some logic..."));
         logic.process_completed.notify();
         
-        assert_eq!(1, logic.process_completed.times_func_subscribers_notified);
+        assert_eq!(1, logic.process_completed.stats().fn_subscribers_notified);
     }
 
     #[test]
     fn closure_is_only_notified_one_time() {
         let config = EventConfig { 
             subscribers_to_notify: Notify::OnlyFnSubscribers, 
-            clear_subscribers_after_notification: Clear::All 
+            clear_subscribers_after_notification: Clear::All,
+            ..Default::default()
         };
 
         let mut logic = ProcessBusinessLogic {
@@ -124,14 +119,15 @@ mod event_tests {
         logic.process_completed.notify();
         logic.process_completed.notify();
         
-        assert_eq!(1, logic.process_completed.times_func_subscribers_notified);
+        assert_eq!(1, logic.process_completed.stats().fn_subscribers_notified);
     }
 
     #[test]
     fn closure_is_notified_twice() {
         let config = EventConfig { 
             subscribers_to_notify: Notify::OnlyFnSubscribers, 
-            clear_subscribers_after_notification: Clear::None 
+            clear_subscribers_after_notification: Clear::None,
+            ..Default::default()
         };
 
         let mut logic = ProcessBusinessLogic {
@@ -143,7 +139,7 @@ mod event_tests {
         logic.process_completed.notify();
         logic.process_completed.notify();
         
-        assert_eq!(2, logic.process_completed.times_func_subscribers_notified);
+        assert_eq!(2, logic.process_completed.stats().fn_subscribers_notified);
     }
 
 }
\ No newline at end of file
@@ -1,9 +1,9 @@
 #[cfg(test)]
 mod macro_tests {
-    use std::rc::Rc;
-    use rustvent::subscriber::Subscriber;
+    use std::{cell::Cell, rc::Rc, sync::{Arc, Mutex}};
+    use rustvent::{event_async::EventAsync, event_future::{block_on, EventFuture}, reentrant::ReentrantEvent, subscriber::Subscriber};
     use rustvent_macros::Event;
-    use rustvent::events::Event;
+    use rustvent::events::{Clear, Event, EventConfig};
 
     #[derive(Event, Default)]
     struct ProcessBusinessLogic {
@@ -36,17 +36,19 @@ mod macro_tests {
         logic.on_process_completed();
         logic.on_process_error();
 
-        assert_eq!(1, logic.process_completed.times_subscribers_notified);
-        assert_eq!(1, logic.process_error.times_subscribers_notified);
+        assert_eq!(1, logic.process_completed.stats().subscribers_notified);
+        assert_eq!(1, logic.process_error.stats().subscribers_notified);
     }
 
     #[test]
     fn event_macro_additional_struct_fields_do_not_have_methods_generated() {
-        let mut logic = ProcessLogic {
+        let logic = ProcessLogic {
             id: 1,
             process_completed: Event::default(),
             process_error: Event::default()
         };        
+
+        assert_eq!(1, logic.id);
     }
 
     #[test]
@@ -75,7 +77,145 @@ mod macro_tests {
         logic.process_completed.subscribe(subscriber.clone());
         logic.notify_process_completed_subscribers();
 
-        assert_eq!(1, logic.process_completed.times_subscribers_notified);
+        assert_eq!(1, logic.process_completed.stats().subscribers_notified);
+    }
+
+    #[test]
+    fn event_macro_recognizes_every_kind_of_event_through_paths() {
+        #[derive(Event, Default)]
+        struct Editor {
+            saved: rustvent::events::Event,
+            closed: EventAsync<()>,
+            renamed: Event<String>,
+            reloaded: ReentrantEvent<u32>,
+        }
+
+        let mut editor = Editor::default();
+        let names = Arc::new(Mutex::new(Vec::new()));
+        let names_clone = names.clone();
+        editor.renamed.subscribe_as_fn_with(move |name| names_clone.lock().unwrap().push(name.clone()));
+        let reloads = Rc::new(Cell::new(0));
+        let reloads_clone = reloads.clone();
+        editor.reloaded.subscribe_as_fn_with(move |times| reloads_clone.set(reloads_clone.get() + times));
+
+        assert!(editor.on_saved().is_ok());
+        assert!(editor.on_closed().is_ok());
+        editor.on_renamed(&String::from("notes.txt"));
+        editor.on_reloaded(&2).unwrap();
+
+        assert_eq!(vec![String::from("notes.txt")], *names.lock().unwrap());
+        assert_eq!(2, reloads.get());
+        assert_eq!(1, editor.saved.stats().notifications);
+        assert_eq!(1, editor.closed.stats().notifications);
+    }
+
+    #[test]
+    fn event_macro_attribute_includes_aliases_and_skips_fields() {
+        type Saved = Event<u32>;
+
+        #[derive(Event)]
+        struct Editor {
+            #[event]
+            saved: Saved,
+            #[event(skip)]
+            closed: Event,
+        }
+
+        // Calling `on_closed` would be ambiguous if the derive had generated it too.
+        trait OnClosed { fn on_closed(&self) -> bool { true } }
+        impl OnClosed for Editor {}
+
+        let mut editor = Editor {
+            saved: Event::new(EventConfig { clear_subscribers_after_notification: Clear::None, ..Default::default() }),
+            closed: Event::default(),
+        };
+        editor.saved.subscribe_as_fn_with(|_| ());
+
+        editor.on_saved(&1);
+        editor.on_saved(&2);
+
+        assert_eq!(2, editor.saved.stats().invocations);
+        assert!(editor.on_closed());
+        assert_eq!(0, editor.closed.stats().notifications);
+    }
+
+    #[test]
+    fn event_macro_skipped_event_future_fields_are_notified_by_hand() {
+        #[derive(Event, Default)]
+        struct Editor {
+            saved: Event,
+            #[event(skip)]
+            synced: EventFuture<u32>,
+        }
+
+        let mut editor = Editor::default();
+        editor.synced.subscribe_as_fn_with(|_| async {});
+
+        editor.on_saved();
+        let report = block_on(editor.synced.notify_with(&1));
+
+        assert!(report.is_ok());
+        assert_eq!(1, editor.synced.stats().invocations);
+    }
+
+    #[test]
+    fn event_macro_supports_generic_structs() {
+        #[derive(Event)]
+        struct Repository<'a, T> where T: Clone + Send + 'static {
+            name: &'a str,
+            added: EventAsync<T>,
+            removed: Event<T>,
+        }
+
+        let mut repository = Repository { name: "orders", added: EventAsync::default(), removed: Event::default() };
+        let added = Arc::new(Mutex::new(Vec::new()));
+        let added_clone = added.clone();
+        repository.added.subscribe_as_fn_with(move |order: &u32| added_clone.lock().unwrap().push(*order));
+
+        assert!(repository.on_added(&7).is_ok());
+        assert!(repository.on_removed(&7).is_ok());
+
+        assert_eq!("orders", repository.name);
+        assert_eq!(vec![7], *added.lock().unwrap());
+    }
+
+    #[test]
+    fn event_macro_generates_methods_for_tuple_struct_fields() {
+        #[derive(Event, Default)]
+        struct Toolbar(u8, Event, Event<&'static str>);
+
+        let mut toolbar = Toolbar::default();
+        let clicked = Rc::new(Cell::new(""));
+        let clicked_clone = clicked.clone();
+        toolbar.2.subscribe_as_fn_with(move |button| clicked_clone.set(button));
+
+        toolbar.on_1();
+        toolbar.on_2(&"save");
+
+        assert_eq!(0, toolbar.0);
+        assert_eq!(1, toolbar.1.stats().notifications);
+        assert_eq!("save", clicked.get());
+    }
+
+    #[test]
+    #[deny(dead_code)]
+    fn event_macro_events_of_private_struct_need_not_all_be_notified() {
+        #[derive(Event, Default)]
+        struct Logic {
+            done: Event,
+            failed: Event
+        }
+
+        let mut logic = Logic::default();
+        logic.on_done();
+
+        assert_eq!(1, logic.done.stats().notifications);
+    }
+
+    #[test]
+    fn event_macro_reports_invalid_uses_as_compile_errors() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/*.rs");
     }
 
-}
\ No newline at end of file
+}
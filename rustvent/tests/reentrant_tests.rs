@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod reentrant_tests {
+    use std::{cell::{Cell, RefCell}, rc::{Rc, Weak}};
+
+    use rustvent::{error::RustventError, reentrant::ReentrantEvent, subscriber::SubscriberMut, subscription::SubscriptionId};
+    use rustvent::events::{Clear, EventConfig, Reentrancy};
+
+    fn event_with(reentrancy: Reentrancy) -> Rc<ReentrantEvent<u32>> {
+        Rc::new(ReentrantEvent::new(EventConfig {
+            clear_subscribers_after_notification: Clear::None,
+            reentrancy,
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn reentrant_event_subscriber_can_unsubscribe_itself() {
+        let event = event_with(Reentrancy::Queue);
+        let calls = Rc::new(Cell::new(0));
+        let own_id = Rc::new(Cell::new(None::<SubscriptionId>));
+
+        let (weak_event, calls_clone, own_id_clone) = (Rc::downgrade(&event), calls.clone(), own_id.clone());
+        let id = event.subscribe_as_fn_with(move |_| {
+            calls_clone.set(calls_clone.get() + 1);
+            weak_event.upgrade().unwrap().unsubscribe(own_id_clone.get().unwrap());
+        });
+        own_id.set(Some(id));
+
+        event.notify_with(1).unwrap();
+        event.notify_with(2).unwrap();
+
+        assert_eq!(1, calls.get());
+        assert!(event.is_empty());
+    }
+
+    #[test]
+    fn reentrant_event_subscriptions_made_during_notification_are_deferred() {
+        let event = event_with(Reentrancy::Queue);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let (weak_event, log_clone) = (Rc::downgrade(&event), log.clone());
+        event.subscribe_as_fn_with(move |args| {
+            log_clone.borrow_mut().push(format!("outer {args}"));
+            let log = log_clone.clone();
+            let event = weak_event.upgrade().unwrap();
+            let before = event.len();
+            event.subscribe_as_fn_with(move |args| log.borrow_mut().push(format!("inner {args}")));
+            assert_eq!(before, event.len());
+        });
+
+        event.notify_with(1).unwrap();
+        assert_eq!(2, event.len());
+
+        event.notify_with(2).unwrap();
+        assert_eq!(vec!["outer 1", "outer 2", "inner 2"], *log.borrow());
+    }
+
+    #[test]
+    fn reentrant_event_queues_notifications_made_by_subscribers() {
+        let event = event_with(Reentrancy::Queue);
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let (weak_event, log_clone) = (Rc::downgrade(&event), log.clone());
+        event.subscribe_as_fn_with(move |attempt| {
+            log_clone.borrow_mut().push(*attempt);
+            if *attempt < 3 {
+                let report = weak_event.upgrade().unwrap().notify_with(attempt + 1).unwrap();
+                assert!(report.is_ok());
+            }
+            log_clone.borrow_mut().push(*attempt + 100);
+        });
+
+        event.notify_with(1).unwrap();
+
+        assert_eq!(vec![1, 101, 2, 102, 3, 103], *log.borrow());
+    }
+
+    #[test]
+    fn reentrant_event_clears_subscribers_once_queued_notifications_completed() {
+        let event: Rc<ReentrantEvent<u32>> = Rc::new(ReentrantEvent::default());
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let (weak_event, log_clone) = (Rc::downgrade(&event), log.clone());
+        event.subscribe_as_fn_with(move |attempt| {
+            log_clone.borrow_mut().push(*attempt);
+            if *attempt < 3 {
+                weak_event.upgrade().unwrap().notify_with(attempt + 1).unwrap();
+            }
+        });
+
+        event.notify_with(0).unwrap();
+
+        assert_eq!(vec![0, 1, 2, 3], *log.borrow());
+        assert!(event.is_empty());
+        assert_eq!(1, Rc::strong_count(&event));
+    }
+
+    #[test]
+    fn reentrant_event_recursion_is_bounded_by_max_depth() {
+        let event = event_with(Reentrancy::Recurse { max_depth: 3 });
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let (weak_event, log_clone) = (Rc::downgrade(&event), log.clone());
+        event.subscribe_as_fn_with(move |depth| {
+            let result = weak_event.upgrade().unwrap().notify_with(depth + 1);
+            log_clone.borrow_mut().push((*depth, result.err()));
+        });
+
+        event.notify_with(1).unwrap();
+
+        assert_eq!(vec![(3, Some(RustventError::MaxDepthExceeded(3))), (2, None), (1, None)], *log.borrow());
+        assert!(!event.is_notifying());
+    }
+
+    #[test]
+    fn reentrant_event_subscriber_mut_is_skipped_by_nested_notification() {
+        struct Retrier {
+            event: Weak<ReentrantEvent<u32>>,
+            attempts: u32,
+        }
+
+        impl SubscriberMut<u32> for Retrier {
+            fn update_mut_with(&mut self, attempt: &u32) {
+                self.attempts += 1;
+                let report = self.event.upgrade().unwrap().notify_with(attempt + 1).unwrap();
+                assert_eq!(1, report.failures().len());
+            }
+        }
+
+        let event = event_with(Reentrancy::Recurse { max_depth: 4 });
+        let retrier = Rc::new(RefCell::new(Retrier { event: Rc::downgrade(&event), attempts: 0 }));
+
+        let id = event.subscribe_mut(retrier.clone());
+        let report = event.notify_with(1).unwrap();
+
+        assert!(report.is_ok());
+        assert_eq!(1, retrier.borrow().attempts);
+        assert_eq!(Ok(()), event.try_unsubscribe(id));
+        assert_eq!(Err(RustventError::SubscriptionNotFound(id)), event.try_unsubscribe(id));
+    }
+}
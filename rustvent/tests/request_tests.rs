@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod request_tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use rustvent::{error::RustventError, subscriber::Responder};
+    use rustvent::request::{AllTrue, AnyTrue, FirstSome, RequestEvent, Sum};
+
+    struct Document {
+        read_only: bool,
+        size: u64,
+    }
+
+    /// Refuses to save read-only documents, counting how often it was asked.
+    #[derive(Default)]
+    struct ReadOnlyGuard {
+        asked: Cell<u32>,
+    }
+
+    impl Responder<bool, Document> for ReadOnlyGuard {
+        fn respond_with(&self, document: &Document) -> bool {
+            self.asked.set(self.asked.get() + 1);
+            !document.read_only
+        }
+    }
+
+    #[test]
+    fn request_event_collects_every_response_by_priority() {
+        let mut event: RequestEvent<&'static str> = RequestEvent::default();
+
+        event.subscribe_as_fn(|| "default");
+        event.subscribe_as_fn_with_priority(|_| "first", 10);
+        event.subscribe_as_fn_with_priority(|_| "last", -10);
+
+        assert_eq!(vec!["first", "default", "last"], event.notify_collect());
+    }
+
+    #[test]
+    fn request_event_all_true_stops_at_first_refusal() {
+        let mut can_save: RequestEvent<bool, Document> = RequestEvent::new();
+        let guard = Rc::new(ReadOnlyGuard::default());
+
+        can_save.subscribe_as_fn_with_priority(|document| document.size < 1024, 1);
+        can_save.subscribe(guard.clone());
+
+        assert!(can_save.notify_reduce_with(&Document { read_only: false, size: 10 }, AllTrue));
+        assert!(!can_save.notify_reduce_with(&Document { read_only: true, size: 10 }, AllTrue));
+        assert!(!can_save.notify_reduce_with(&Document { read_only: false, size: 4096 }, AllTrue));
+        assert_eq!(2, guard.asked.get());
+    }
+
+    #[test]
+    fn request_event_without_responders_reduces_to_neutral_answer() {
+        let event: RequestEvent<bool> = RequestEvent::new();
+
+        assert!(event.notify_reduce(AllTrue));
+        assert!(!event.notify_reduce(AnyTrue));
+        assert!(event.notify_collect().is_empty());
+    }
+
+    #[test]
+    fn request_event_first_some_skips_responders_without_answer() {
+        let mut resolve: RequestEvent<Option<u32>, String> = RequestEvent::new();
+        let asked = Rc::new(Cell::new(0));
+        let asked_clone = asked.clone();
+
+        resolve.subscribe_as_fn_with(|_| None);
+        resolve.subscribe_as_fn_with(|name| name.parse().ok());
+        resolve.subscribe_as_fn_with(move |_| { asked_clone.set(asked_clone.get() + 1); Some(0) });
+
+        assert_eq!(Some(42), resolve.notify_reduce_with(&"42".to_string(), FirstSome));
+        assert_eq!(0, asked.get());
+        assert_eq!(Some(0), resolve.notify_reduce_with(&"answer".to_string(), FirstSome));
+    }
+
+    #[test]
+    fn request_event_sums_and_folds_responses() {
+        let mut total_size: RequestEvent<u64> = RequestEvent::new();
+
+        total_size.subscribe_as_fn(|| 512);
+        let id = total_size.subscribe_as_fn(|| 1024);
+
+        assert_eq!(1536, total_size.notify_reduce(Sum));
+        assert_eq!(1024, total_size.notify_fold(0, u64::max));
+
+        total_size.unsubscribe(id);
+
+        assert_eq!(512, total_size.notify_reduce(Sum));
+        assert_eq!(Err(RustventError::SubscriptionNotFound(id)), total_size.try_unsubscribe(id));
+    }
+}
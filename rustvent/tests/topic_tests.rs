@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod topic_tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use rustvent::{error::RustventError, subscriber::Subscriber, topic::{TopicMessage, TopicRouter}};
+
+    /// Records the topics it is notified on.
+    #[derive(Default)]
+    struct Recorder {
+        topics: RefCell<Vec<String>>,
+    }
+
+    impl Subscriber<TopicMessage<u32>> for Recorder {
+        fn update_with(&self, message: &TopicMessage<u32>) {
+            self.topics.borrow_mut().push(message.topic.clone());
+        }
+    }
+
+    fn router_with(pattern: &str, recorder: &Rc<Recorder>) -> TopicRouter<u32> {
+        let mut router = TopicRouter::new();
+        router.subscribe(pattern, recorder.clone());
+        router
+    }
+
+    #[test]
+    fn topic_router_exact_pattern_only_matches_its_topic() {
+        let recorder = Rc::new(Recorder::default());
+        let mut router = router_with("orders.eu.created", &recorder);
+
+        router.publish("orders.eu.created", 1);
+        router.publish("orders.eu.cancelled", 2);
+        router.publish("orders.eu", 3);
+
+        assert_eq!(vec!["orders.eu.created"], *recorder.topics.borrow());
+    }
+
+    #[test]
+    fn topic_router_single_wildcard_matches_one_segment() {
+        let recorder = Rc::new(Recorder::default());
+        let mut router = router_with("orders.*", &recorder);
+
+        router.publish("orders.created", 1);
+        router.publish("orders.eu.created", 2);
+        router.publish("orders", 3);
+
+        assert_eq!(vec!["orders.created"], *recorder.topics.borrow());
+    }
+
+    #[test]
+    fn topic_router_multi_wildcard_matches_zero_or_more_segments() {
+        let recorder = Rc::new(Recorder::default());
+        let mut router = router_with("orders.#", &recorder);
+
+        router.publish("orders", 1);
+        router.publish("orders.created", 2);
+        router.publish("orders.eu.created", 3);
+        router.publish("invoices.created", 4);
+
+        assert_eq!(vec!["orders", "orders.created", "orders.eu.created"], *recorder.topics.borrow());
+    }
+
+    #[test]
+    fn topic_router_multi_wildcard_matches_within_a_pattern() {
+        let recorder = Rc::new(Recorder::default());
+        let mut router = router_with("#.created", &recorder);
+
+        router.publish("created", 1);
+        router.publish("orders.eu.created", 2);
+        router.publish("orders.eu.cancelled", 3);
+
+        assert_eq!(vec!["created", "orders.eu.created"], *recorder.topics.borrow());
+    }
+
+    #[test]
+    fn topic_router_notifies_subscriber_once_when_pattern_matches_several_ways() {
+        let recorder = Rc::new(Recorder::default());
+        let mut router = router_with("#.eu.#", &recorder);
+
+        router.publish("eu.orders.eu.created", 1);
+
+        assert_eq!(1, recorder.topics.borrow().len());
+    }
+
+    #[test]
+    fn topic_router_notifies_every_matching_pattern_with_the_payload() {
+        let mut router: TopicRouter<u32> = TopicRouter::new();
+        let received = Rc::new(RefCell::new(Vec::new()));
+
+        for pattern in ["orders.eu.created", "orders.*.created", "orders.#", "invoices.#"] {
+            let received = received.clone();
+            router.subscribe_as_fn(pattern, move |message| received.borrow_mut().push(message.payload));
+        }
+        router.publish("orders.eu.created", 7);
+
+        assert_eq!(vec![7, 7, 7], *received.borrow());
+        assert_eq!(vec!["orders.#", "orders.*.created", "orders.eu.created"], router.matching_patterns("orders.eu.created"));
+    }
+
+    #[test]
+    fn topic_router_unsubscribe_removes_pattern_without_subscribers() {
+        let recorder = Rc::new(Recorder::default());
+        let mut router = router_with("orders.*", &recorder);
+        let id = router.subscribe("orders.*", recorder.clone());
+
+        router.unsubscribe(id);
+        assert_eq!(vec!["orders.*"], router.matching_patterns("orders.created"));
+
+        let remaining = router.subscribe("orders.#", recorder.clone());
+        router.unsubscribe(remaining);
+
+        assert_eq!(Err(RustventError::SubscriptionNotFound(id)), router.try_unsubscribe(id));
+        assert_eq!(vec!["orders.*"], router.matching_patterns("orders.created"));
+    }
+
+    #[test]
+    fn topic_router_routes_among_thousands_of_patterns() {
+        let recorder = Rc::new(Recorder::default());
+        let mut router = TopicRouter::new();
+
+        for service in 0..1000 {
+            router.subscribe(&format!("telemetry.service-{service}.cpu"), recorder.clone());
+            router.subscribe(&format!("telemetry.service-{service}.*"), recorder.clone());
+        }
+        router.subscribe("telemetry.#", recorder.clone());
+        router.publish("telemetry.service-42.cpu", 1);
+
+        assert_eq!(3, recorder.topics.borrow().len());
+    }
+}
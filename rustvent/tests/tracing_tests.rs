@@ -0,0 +1,110 @@
+#[cfg(all(test, feature = "tracing"))]
+mod tracing_tests {
+    use std::{fmt::Debug, rc::Rc, sync::{Arc, Mutex, atomic::{AtomicU64, Ordering}}};
+
+    use rustvent::{event_async::EventAsync, subscriber::Subscriber};
+    use rustvent::events::{Clear, Dispatch, Event, EventConfig};
+    use tracing::{field::{Field, Visit}, span, Event as TracingEvent, Metadata};
+
+    /// Records every span and event as a line of text, e.g. `notify event="saved"`.
+    #[derive(Default, Clone)]
+    struct Recorder {
+        lines: Arc<Mutex<Vec<String>>>,
+        next_id: Arc<AtomicU64>,
+    }
+
+    struct Line(String);
+
+    impl Visit for Line {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            match field.name() {
+                "message" => self.0.push_str(&format!(" {value:?}")),
+                name => self.0.push_str(&format!(" {name}={value:?}")),
+            }
+        }
+    }
+
+    impl Recorder {
+        fn lines(&self) -> Vec<String> {
+            self.lines.lock().unwrap().clone()
+        }
+
+        fn contains(&self, needle: &str) -> bool {
+            self.lines().iter().any(|line| line.contains(needle))
+        }
+    }
+
+    impl tracing::Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &span::Attributes<'_>) -> span::Id {
+            let mut line = Line(format!("span {}", span.metadata().name()));
+            span.record(&mut line);
+            self.lines.lock().unwrap().push(line.0);
+            span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &TracingEvent<'_>) {
+            let mut line = Line(String::from("event"));
+            event.record(&mut line);
+            self.lines.lock().unwrap().push(line.0);
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    struct Indexer {}
+    impl Subscriber for Indexer {
+        fn update(&self) {
+            panic!("index is locked");
+        }
+    }
+
+    #[test]
+    fn event_traces_subscriptions_notifications_and_panics() {
+        let recorder = Recorder::default();
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut saved: Event = Event::new(EventConfig { name: Some("saved"), catch_panics: true, ..Default::default() });
+            let indexer = saved.subscribe(Rc::new(Indexer {}));
+            let closure = saved.subscribe_as_fn(|| ());
+            saved.unsubscribe(closure);
+            saved.notify();
+
+            assert!(recorder.contains(&format!("event subscribed event=\"saved\" subscriber={indexer:?} kind=\"subscriber\"")));
+            assert!(recorder.contains(&format!("event unsubscribed event=\"saved\" subscriber={closure:?}")));
+            assert!(recorder.contains("span notify event=\"saved\""));
+            assert!(recorder.contains(&format!("span subscriber subscriber={indexer:?}")));
+            assert!(recorder.contains(&format!("event subscriber panicked subscriber={indexer:?} panic=index is locked")));
+            assert!(recorder.contains("event cleared event=\"saved\""));
+            assert!(recorder.contains("event notify completed ok=false"));
+        });
+    }
+
+    #[test]
+    fn event_async_traces_each_subscriber_notified() {
+        let recorder = Recorder::default();
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut saved: EventAsync<u32> = EventAsync::new(EventConfig {
+                clear_subscribers_after_notification: Clear::None,
+                dispatch: Dispatch::Sequential,
+                ..Default::default()
+            });
+            let id = saved.subscribe_as_fn_with(|_| ());
+            saved.notify_with(&1);
+
+            assert!(recorder.contains("span notify event=\"u32\""));
+            assert!(recorder.contains(&format!("event subscriber notified subscriber={id:?}")));
+            assert!(!recorder.contains("event cleared"));
+        });
+    }
+}
@@ -0,0 +1,11 @@
+use rustvent::events::Event;
+use rustvent_macros::Event;
+
+#[derive(Event)]
+struct Editor {
+    #[event]
+    #[event(skip)]
+    saved: Event,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rustvent::events::Event;
+use rustvent_macros::Event;
+
+#[derive(Event)]
+enum Editor {
+    Saved(Event),
+    Closed(Event),
+}
+
+fn main() {}
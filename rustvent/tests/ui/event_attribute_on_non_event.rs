@@ -0,0 +1,9 @@
+use rustvent_macros::Event;
+
+#[derive(Event)]
+struct Editor {
+    #[event]
+    saved: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rustvent::{event_future::EventFuture, events::Event};
+use rustvent_macros::Event;
+
+#[derive(Event)]
+struct Editor {
+    saved: Event,
+    synced: EventFuture<u32>,
+}
+
+fn main() {}
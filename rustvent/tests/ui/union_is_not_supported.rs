@@ -0,0 +1,9 @@
+use rustvent_macros::Event;
+
+#[derive(Event)]
+union Editor {
+    saved: u32,
+    closed: f32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use rustvent::events::Event;
+use rustvent_macros::Event;
+
+#[derive(Event)]
+struct Editor {
+    #[event(rename = "on_save")]
+    saved: Event,
+    #[event(ignore)]
+    closed: Event,
+}
+
+fn main() {}